@@ -0,0 +1,94 @@
+//! Block buffering with chaining across blocks (CBC/IGE-style modes).
+
+use core::cmp::min;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Buffer for block processing of data in modes which need the previous
+/// (processed) block alongside the current one, e.g. CBC or IGE.
+///
+/// Input is buffered into blocks and the processing function is called with
+/// `(prev_block, current_block_mut)` pairs. After the callback returns, the
+/// (possibly modified) current block becomes the carry block passed as
+/// `prev_block` on the next invocation, so e.g. CBC encryption can XOR and
+/// encrypt in place without keeping an extra block around.
+#[derive(Clone, Default)]
+pub struct ChainingBuffer<BlockSize: ArrayLength<u8>> {
+    buffer: GenericArray<u8, BlockSize>,
+    prev: GenericArray<u8, BlockSize>,
+    pos: usize,
+}
+
+impl<BlockSize: ArrayLength<u8>> ChainingBuffer<BlockSize> {
+    /// Create a new buffer with the provided initialization vector as the
+    /// initial carry block.
+    #[inline]
+    pub fn new(iv: &GenericArray<u8, BlockSize>) -> Self {
+        Self {
+            buffer: Default::default(),
+            prev: iv.clone(),
+            pos: 0,
+        }
+    }
+
+    /// Process data in `input` in blocks of size `BlockSize` using function
+    /// `f`, which receives the previous processed block and the current
+    /// block for in-place modification.
+    #[inline]
+    pub fn input_block(
+        &mut self,
+        mut input: &[u8],
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>, &mut GenericArray<u8, BlockSize>),
+    ) {
+        while !input.is_empty() {
+            let n = min(self.remaining(), input.len());
+            let (l, r) = input.split_at(n);
+            self.buffer[self.pos..self.pos + n].copy_from_slice(l);
+            self.pos += n;
+            input = r;
+            if self.pos == self.size() {
+                f(&self.prev, &mut self.buffer);
+                self.prev.clone_from(&self.buffer);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Return the current carry block, i.e. the previous processed block
+    /// (or the IV if no block has been processed yet).
+    #[inline]
+    pub fn prev_block(&self) -> &GenericArray<u8, BlockSize> {
+        &self.prev
+    }
+
+    /// Replace the carry block, e.g. when re-keying or seeking.
+    #[inline]
+    pub fn set_prev_block(&mut self, block: &GenericArray<u8, BlockSize>) {
+        self.prev.clone_from(block);
+    }
+
+    /// Return size of the internall buffer in bytes
+    #[inline]
+    pub fn size(&self) -> usize {
+        BlockSize::to_usize()
+    }
+
+    /// Return current cursor position
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Return number of remaining bytes in the internall buffer
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.size() - self.pos
+    }
+
+    /// Reset buffer by setting cursor position to zero and replacing the
+    /// carry block with the provided IV.
+    #[inline]
+    pub fn reset(&mut self, iv: &GenericArray<u8, BlockSize>) {
+        self.pos = 0;
+        self.prev.clone_from(iv);
+    }
+}
@@ -0,0 +1,79 @@
+//! Constant-time block buffering.
+
+use core::cmp::min;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Buffer for block processing of data with a uniform memory access pattern.
+///
+/// Unlike [`BlockBuffer`][`crate::BlockBuffer`], which passes block-aligned
+/// chunks of the input slice directly to the processing function, this buffer
+/// always copies data through its internal block first. As a result:
+///
+/// - Control flow depends only on the *lengths* of the input slices and the
+///   cursor position derived from them, never on the buffered byte values.
+/// - Memory is always read and written at addresses determined solely by the
+///   cursor position, so the access pattern is a function of the input
+///   lengths alone.
+///
+/// # What is and isn't constant time
+///
+/// Input lengths (and therefore the cursor position) are treated as *public*
+/// values: callers for which the total message length itself is secret get no
+/// protection from this type. The buffered data bytes are never used in
+/// branch conditions or address computations, but the processing function `f`
+/// supplied by the caller is outside of this type's control and must uphold
+/// its own constant-time guarantees.
+#[derive(Clone, Default)]
+pub struct CtBlockBuffer<BlockSize: ArrayLength<u8>> {
+    buffer: GenericArray<u8, BlockSize>,
+    pos: usize,
+}
+
+impl<BlockSize: ArrayLength<u8>> CtBlockBuffer<BlockSize> {
+    /// Process data in `input` in blocks of size `BlockSize` using function `f`.
+    ///
+    /// Every block is staged in the internal buffer before being passed to
+    /// `f`, keeping the access pattern independent of the input split points.
+    #[inline]
+    pub fn input_block(
+        &mut self,
+        mut input: &[u8],
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        while !input.is_empty() {
+            let n = min(self.remaining(), input.len());
+            let (l, r) = input.split_at(n);
+            self.buffer[self.pos..self.pos + n].copy_from_slice(l);
+            self.pos += n;
+            input = r;
+            if self.pos == self.size() {
+                f(&self.buffer);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Return size of the internall buffer in bytes
+    #[inline]
+    pub fn size(&self) -> usize {
+        BlockSize::to_usize()
+    }
+
+    /// Return current cursor position
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Return number of remaining bytes in the internall buffer
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.size() - self.pos
+    }
+
+    /// Reset buffer by setting cursor position to zero
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos = 0
+    }
+}
@@ -0,0 +1,54 @@
+//! De-multiplexing of a single input stream into several lane buffers.
+
+use crate::BlockBuffer;
+use core::cmp::min;
+use generic_array::{ArrayLength, GenericArray};
+
+/// De-multiplexer which distributes one input stream round-robin into `N`
+/// lane buffers in `BlockSize` strides.
+///
+/// This is the data layout used by N-way SIMD hashing of a single long
+/// message (e.g. ParallelHash): block `i` of the message goes to lane
+/// `i % N`. Partial strides at the end of an input slice are buffered in
+/// the corresponding lane and completed by subsequent calls, so input can
+/// be fed in chunks of arbitrary length.
+#[derive(Clone, Default)]
+pub struct LaneDemux {
+    /// Index of the lane which receives the next input byte
+    lane: usize,
+}
+
+impl LaneDemux {
+    /// Distribute `input` into `lanes`, calling `f` with the lane index and
+    /// block contents whenever a lane completes a block.
+    ///
+    /// # Panics
+    /// If `lanes` is empty.
+    #[inline]
+    pub fn input_blocks<BlockSize: ArrayLength<u8>>(
+        &mut self,
+        mut input: &[u8],
+        lanes: &mut [BlockBuffer<BlockSize>],
+        mut f: impl FnMut(usize, &GenericArray<u8, BlockSize>),
+    ) {
+        assert!(!lanes.is_empty(), "lanes must not be empty");
+        self.lane %= lanes.len();
+        while !input.is_empty() {
+            let lane = self.lane;
+            let n = min(lanes[lane].remaining(), input.len());
+            let (l, r) = input.split_at(n);
+            let mut completed = false;
+            lanes[lane].input_block(l, |block| {
+                completed = true;
+                f(lane, block);
+            });
+            // `n` is capped at the lane's remaining capacity, so at most one
+            // block completes per iteration; a partial tail stays buffered in
+            // the current lane until more input arrives.
+            if completed {
+                self.lane = (lane + 1) % lanes.len();
+            }
+            input = r;
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Instrumented wrapper around `BlockBuffer` for profiling and testing.
+
+use crate::BlockBuffer;
+use generic_array::{ArrayLength, GenericArray};
+
+/// `BlockBuffer` wrapper which counts invocations of the compression
+/// callback.
+///
+/// The counters make it possible to write regression tests asserting
+/// properties like "exactly one compress call for block-aligned input" or
+/// to profile how well input chunking amortizes callback overhead.
+#[derive(Clone, Default)]
+pub struct InstrumentedBuffer<BlockSize: ArrayLength<u8>> {
+    inner: BlockBuffer<BlockSize>,
+    compress_calls: u64,
+    blocks_processed: u64,
+    max_blocks_per_call: usize,
+}
+
+impl<BlockSize: ArrayLength<u8>> InstrumentedBuffer<BlockSize> {
+    /// Record a callback invocation processing `n` blocks.
+    #[inline]
+    fn record(&mut self, n: usize) {
+        self.compress_calls += 1;
+        self.blocks_processed += n as u64;
+        if n > self.max_blocks_per_call {
+            self.max_blocks_per_call = n;
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::input_block`].
+    #[inline]
+    pub fn input_block(
+        &mut self,
+        input: &[u8],
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        let mut calls = 0;
+        self.inner.input_block(input, |block| {
+            calls += 1;
+            f(block);
+        });
+        for _ in 0..calls {
+            self.record(1);
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::input_blocks`].
+    #[inline]
+    pub fn input_blocks(
+        &mut self,
+        input: &[u8],
+        mut f: impl FnMut(&[GenericArray<u8, BlockSize>]),
+    ) {
+        let mut calls = [0usize; 2];
+        let mut n_calls = 0;
+        self.inner.input_blocks(input, |blocks| {
+            calls[n_calls] = blocks.len();
+            n_calls += 1;
+            f(blocks);
+        });
+        for &n in &calls[..n_calls] {
+            self.record(n);
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::input_lazy`].
+    #[inline]
+    pub fn input_lazy(
+        &mut self,
+        input: &[u8],
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        let mut calls = 0;
+        self.inner.input_lazy(input, |block| {
+            calls += 1;
+            f(block);
+        });
+        for _ in 0..calls {
+            self.record(1);
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::len64_padding_be`].
+    #[inline]
+    pub fn len64_padding_be(
+        &mut self,
+        data_len: u64,
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        let mut calls = 0;
+        self.inner.len64_padding_be(data_len, |block| {
+            calls += 1;
+            f(block);
+        });
+        for _ in 0..calls {
+            self.record(1);
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::len64_padding_le`].
+    #[inline]
+    pub fn len64_padding_le(
+        &mut self,
+        data_len: u64,
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        let mut calls = 0;
+        self.inner.len64_padding_le(data_len, |block| {
+            calls += 1;
+            f(block);
+        });
+        for _ in 0..calls {
+            self.record(1);
+        }
+    }
+
+    /// Instrumented [`BlockBuffer::len128_padding_be`].
+    #[inline]
+    pub fn len128_padding_be(
+        &mut self,
+        data_len: u128,
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        let mut calls = 0;
+        self.inner.len128_padding_be(data_len, |block| {
+            calls += 1;
+            f(block);
+        });
+        for _ in 0..calls {
+            self.record(1);
+        }
+    }
+
+    /// Return total number of compression callback invocations
+    #[inline]
+    pub fn compress_calls(&self) -> u64 {
+        self.compress_calls
+    }
+
+    /// Return total number of blocks passed to the callback
+    #[inline]
+    pub fn blocks_processed(&self) -> u64 {
+        self.blocks_processed
+    }
+
+    /// Return maximum number of blocks handed to the callback in one call
+    #[inline]
+    pub fn max_blocks_per_call(&self) -> usize {
+        self.max_blocks_per_call
+    }
+
+    /// Return current cursor position of the wrapped buffer
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.inner.position()
+    }
+
+    /// Reset the wrapped buffer and all counters
+    #[inline]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+        self.compress_calls = 0;
+        self.blocks_processed = 0;
+        self.max_blocks_per_call = 0;
+    }
+}
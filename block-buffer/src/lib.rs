@@ -3,11 +3,55 @@
 pub use block_padding;
 pub use generic_array;
 
+#[cfg(feature = "test-utils")]
+extern crate alloc;
+
+#[cfg(feature = "ct")]
+mod ct;
+mod chain;
+mod demux;
+mod instrument;
+
+#[cfg(feature = "staging")]
+mod staging;
+
+#[cfg(feature = "ct")]
+pub use crate::ct::CtBlockBuffer;
+pub use crate::chain::ChainingBuffer;
+pub use crate::demux::LaneDemux;
+pub use crate::instrument::InstrumentedBuffer;
+#[cfg(feature = "staging")]
+pub use crate::staging::StagingBuffer;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 #[cfg(feature = "block-padding")]
-use block_padding::{PadError, Padding};
-use core::{convert::TryInto, slice};
+use block_padding::{DynPadding, PadError, Padding};
+use core::{convert::TryInto, fmt, slice};
 use generic_array::{ArrayLength, GenericArray};
 
+/// Error type used by the checked `BlockBuffer` operations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Cursor position is outside of the internal buffer
+    InvalidPos,
+    /// Message length counter has overflowed
+    LengthOverflow,
+    /// Internal buffer is too small for the provided data
+    BufferTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPos => f.write_str("invalid cursor position"),
+            Error::LengthOverflow => f.write_str("message length overflow"),
+            Error::BufferTooSmall => f.write_str("buffer too small"),
+        }
+    }
+}
+
 /// Buffer for block processing of data
 #[derive(Clone, Default)]
 pub struct BlockBuffer<BlockSize: ArrayLength<u8>> {
@@ -15,6 +59,20 @@ pub struct BlockBuffer<BlockSize: ArrayLength<u8>> {
     pos: usize,
 }
 
+/// Formats buffer size and cursor position only: buffered data may contain
+/// secrets and is never printed.
+#[cfg(feature = "defmt")]
+impl<BlockSize: ArrayLength<u8>> defmt::Format for BlockBuffer<BlockSize> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "BlockBuffer {{ size: {}, pos: {} }}",
+            self.size(),
+            self.pos
+        );
+    }
+}
+
 impl<BlockSize: ArrayLength<u8>> BlockBuffer<BlockSize> {
     /// Process data in `input` in blocks of size `BlockSize` using function `f`.
     #[inline]
@@ -192,6 +250,22 @@ impl<BlockSize: ArrayLength<u8>> BlockBuffer<BlockSize> {
         self.pos = 0;
     }
 
+    /// Pad message with 0x80, zeros and 128-bit message length
+    /// using little-endian byte order
+    #[inline]
+    pub fn len128_padding_le(
+        &mut self,
+        data_len: u128,
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) {
+        self.digest_pad(16, &mut f);
+        let b = data_len.to_le_bytes();
+        let n = self.buffer.len() - b.len();
+        self.buffer[n..].copy_from_slice(&b);
+        f(&self.buffer);
+        self.pos = 0;
+    }
+
     /// Pad message with a given padding `P`
     ///
     /// Returns `PadError` if internall buffer is full, which can only happen if
@@ -204,6 +278,51 @@ impl<BlockSize: ArrayLength<u8>> BlockBuffer<BlockSize> {
         Ok(&mut self.buffer)
     }
 
+    /// Pad message with a runtime-selected padding scheme
+    ///
+    /// Variant of [`pad_with`][BlockBuffer::pad_with] for cipher wrappers
+    /// which negotiate the padding scheme at runtime, e.g. via
+    /// `block_padding::PaddingScheme`. Returns `PadError` if internall
+    /// buffer is full, which can only happen if `input_lazy` was used.
+    #[cfg(feature = "block-padding")]
+    #[inline]
+    pub fn pad_with_dyn(
+        &mut self,
+        padding: &dyn DynPadding,
+    ) -> Result<&mut GenericArray<u8, BlockSize>, PadError> {
+        padding.pad_block(&mut self.buffer[..], self.pos)?;
+        self.pos = 0;
+        Ok(&mut self.buffer)
+    }
+
+    /// Pad the message with padding `P`, always terminating it, and process
+    /// the resulting block(s) using function `f`.
+    ///
+    /// If the internal buffer is full (e.g. after `input_lazy`), it's
+    /// processed first and the padding is written into a fresh block.
+    /// Block-aligned messages receive a full block of padding for schemes
+    /// which define one; schemes which never emit padding (e.g.
+    /// `NoPadding`) produce no termination block, so `f` is called between
+    /// zero and two times.
+    #[cfg(feature = "block-padding")]
+    #[inline]
+    pub fn pad_blocks_with<P: Padding>(
+        &mut self,
+        mut f: impl FnMut(&GenericArray<u8, BlockSize>),
+    ) -> Result<(), PadError> {
+        if self.pos == self.size() {
+            f(&self.buffer);
+            self.pos = 0;
+        }
+        let block_size = self.size();
+        let padded_len = P::pad_blocks(&mut self.buffer[..], self.pos, block_size)?.len();
+        self.pos = 0;
+        if padded_len != 0 {
+            f(&self.buffer);
+        }
+        Ok(())
+    }
+
     /// Return size of the internall buffer in bytes
     #[inline]
     pub fn size(&self) -> usize {
@@ -227,6 +346,47 @@ impl<BlockSize: ArrayLength<u8>> BlockBuffer<BlockSize> {
     pub fn reset(&mut self) {
         self.pos = 0
     }
+
+    /// Create a buffer with `data` already buffered in it.
+    ///
+    /// Returns `Error::BufferTooSmall` if `data` does not fit into
+    /// a single block.
+    #[inline]
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, Error> {
+        let mut buf = Self::default();
+        if data.len() > buf.size() {
+            return Err(Error::BufferTooSmall);
+        }
+        buf.buffer[..data.len()].copy_from_slice(data);
+        buf.pos = data.len();
+        Ok(buf)
+    }
+
+    /// Set cursor position, checking that it's inside of the internal buffer.
+    ///
+    /// Returns `Error::InvalidPos` if `pos` is bigger than the buffer size.
+    #[inline]
+    pub fn try_set_position(&mut self, pos: usize) -> Result<(), Error> {
+        if pos > self.size() {
+            return Err(Error::InvalidPos);
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
+    /// Compute total message length in bits for `blocks_processed` full
+    /// blocks plus the currently buffered tail.
+    ///
+    /// Returns `Error::LengthOverflow` if the length does not fit
+    /// into `u64`, instead of silently wrapping around.
+    #[inline]
+    pub fn try_bit_len(&self, blocks_processed: u64) -> Result<u64, Error> {
+        blocks_processed
+            .checked_mul(self.size() as u64)
+            .and_then(|bytes| bytes.checked_add(self.pos as u64))
+            .and_then(|bytes| bytes.checked_mul(8))
+            .ok_or(Error::LengthOverflow)
+    }
 }
 
 /// Sets all bytes in `dst` to zero
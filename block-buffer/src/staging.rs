@@ -0,0 +1,100 @@
+//! Multi-block staging buffer.
+
+use core::cmp::min;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Buffer which stages up to `CAPACITY` blocks of data contiguously before
+/// handing them off for processing in one batch.
+///
+/// This is useful on embedded targets with DMA engines, where kicking off
+/// one transfer per staged batch is much cheaper than one per block. No
+/// heap allocation is performed: storage is a const-generic array of
+/// blocks.
+#[derive(Clone)]
+pub struct StagingBuffer<BlockSize: ArrayLength<u8>, const CAPACITY: usize> {
+    blocks: [GenericArray<u8, BlockSize>; CAPACITY],
+    /// Number of staged bytes
+    len: usize,
+}
+
+impl<BlockSize: ArrayLength<u8>, const CAPACITY: usize> Default
+    for StagingBuffer<BlockSize, CAPACITY>
+{
+    fn default() -> Self {
+        Self {
+            blocks: [(); CAPACITY].map(|_| GenericArray::default()),
+            len: 0,
+        }
+    }
+}
+
+impl<BlockSize: ArrayLength<u8>, const CAPACITY: usize> StagingBuffer<BlockSize, CAPACITY> {
+    /// Copy as much of `input` as fits into the staging area, returning the
+    /// number of bytes accepted.
+    ///
+    /// Once the buffer is full, call [`take_full_blocks`][Self::take_full_blocks]
+    /// and push the rest of the input afterwards.
+    #[inline]
+    pub fn push_data(&mut self, input: &[u8]) -> usize {
+        let bs = self.block_size();
+        let mut accepted = 0;
+        while accepted < input.len() && self.len < self.capacity() {
+            let (block, off) = (self.len / bs, self.len % bs);
+            let n = min(bs - off, input.len() - accepted);
+            self.blocks[block][off..off + n].copy_from_slice(&input[accepted..accepted + n]);
+            self.len += n;
+            accepted += n;
+        }
+        accepted
+    }
+
+    /// Pass all fully staged blocks to `f` as one contiguous slice, then
+    /// retain any partial trailing block as the start of the next batch.
+    #[inline]
+    pub fn take_full_blocks(&mut self, f: impl FnOnce(&[GenericArray<u8, BlockSize>])) {
+        let bs = self.block_size();
+        let (n_full, off) = (self.len / bs, self.len % bs);
+        f(&self.blocks[..n_full]);
+        if n_full != 0 && off != 0 {
+            let tail = self.blocks[n_full].clone();
+            self.blocks[0][..off].copy_from_slice(&tail[..off]);
+        }
+        self.len = off;
+    }
+
+    /// Return size of a single block in bytes
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        BlockSize::to_usize()
+    }
+
+    /// Return total capacity of the staging area in bytes
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.block_size() * CAPACITY
+    }
+
+    /// Return number of bytes currently staged
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no data is staged
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the staging area is full
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Reset buffer by discarding all staged data
+    #[inline]
+    pub fn reset(&mut self) {
+        self.len = 0
+    }
+}
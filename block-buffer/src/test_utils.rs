@@ -0,0 +1,142 @@
+//! Property-testing utilities for `BlockBuffer`.
+//!
+//! This module provides a [`CheckedBuffer`] wrapper which mirrors every
+//! buffering operation against a naive `Vec`-based reference model and
+//! panics on any divergence, plus `proptest` strategies for generating
+//! inputs split at random chunk boundaries. Hash and MAC crates can use
+//! these to catch chunk-boundary bugs which only manifest for particular
+//! input split points.
+
+use crate::BlockBuffer;
+use alloc::vec::Vec;
+use generic_array::ArrayLength;
+use proptest::collection::vec as prop_vec;
+use proptest::prelude::*;
+
+/// `BlockBuffer` wrapper which checks every operation against a naive
+/// `Vec`-based reference model.
+///
+/// The model tracks the bytes which have been buffered but not yet passed
+/// to the processing callback. After every operation the wrapper asserts
+/// that the blocks emitted by the real buffer match the reference model
+/// byte-for-byte and that the cursor position agrees with the model.
+#[derive(Clone, Default)]
+pub struct CheckedBuffer<BlockSize: ArrayLength<u8>> {
+    inner: BlockBuffer<BlockSize>,
+    /// Bytes buffered but not yet emitted according to the reference model
+    model: Vec<u8>,
+}
+
+impl<BlockSize: ArrayLength<u8>> CheckedBuffer<BlockSize> {
+    /// Mirror of [`BlockBuffer::input_block`].
+    pub fn input_block(&mut self, input: &[u8]) {
+        let mut out = Vec::new();
+        self.inner.input_block(input, |b| out.extend_from_slice(b));
+        self.model.extend_from_slice(input);
+        let n = self.inner.size() * (self.model.len() / self.inner.size());
+        let expected: Vec<u8> = self.model.drain(..n).collect();
+        assert_eq!(out, expected, "emitted blocks diverge from model");
+        self.check_position();
+    }
+
+    /// Mirror of [`BlockBuffer::input_blocks`].
+    pub fn input_blocks(&mut self, input: &[u8]) {
+        let mut out = Vec::new();
+        self.inner.input_blocks(input, |blocks| {
+            for b in blocks {
+                out.extend_from_slice(b);
+            }
+        });
+        self.model.extend_from_slice(input);
+        let n = self.inner.size() * (self.model.len() / self.inner.size());
+        let expected: Vec<u8> = self.model.drain(..n).collect();
+        assert_eq!(out, expected, "emitted blocks diverge from model");
+        self.check_position();
+    }
+
+    /// Mirror of [`BlockBuffer::input_lazy`].
+    pub fn input_lazy(&mut self, input: &[u8]) {
+        let mut out = Vec::new();
+        self.inner.input_lazy(input, |b| out.extend_from_slice(b));
+        self.model.extend_from_slice(input);
+        // `input_lazy` retains a full buffer until more data arrives
+        let n_blocks = self.model.len().saturating_sub(1) / self.inner.size();
+        let n = self.inner.size() * n_blocks;
+        let expected: Vec<u8> = self.model.drain(..n).collect();
+        assert_eq!(out, expected, "emitted blocks diverge from model");
+        self.check_position();
+    }
+
+    /// Mirror of [`BlockBuffer::len64_padding_be`].
+    pub fn len64_padding_be(&mut self, data_len: u64) {
+        let mut out = Vec::new();
+        self.inner.len64_padding_be(data_len, |b| {
+            out.extend_from_slice(b);
+        });
+        self.check_padding(&out, &data_len.to_be_bytes());
+    }
+
+    /// Mirror of [`BlockBuffer::len64_padding_le`].
+    pub fn len64_padding_le(&mut self, data_len: u64) {
+        let mut out = Vec::new();
+        self.inner.len64_padding_le(data_len, |b| {
+            out.extend_from_slice(b);
+        });
+        self.check_padding(&out, &data_len.to_le_bytes());
+    }
+
+    /// Mirror of [`BlockBuffer::len128_padding_be`].
+    pub fn len128_padding_be(&mut self, data_len: u128) {
+        let mut out = Vec::new();
+        self.inner.len128_padding_be(data_len, |b| {
+            out.extend_from_slice(b);
+        });
+        self.check_padding(&out, &data_len.to_be_bytes());
+    }
+
+    /// Mirror of [`BlockBuffer::reset`].
+    pub fn reset(&mut self) {
+        self.inner.reset();
+        self.model.clear();
+        self.check_position();
+    }
+
+    /// Current cursor position of the wrapped buffer.
+    pub fn position(&self) -> usize {
+        self.inner.position()
+    }
+
+    /// Check emitted padding blocks against the reference model.
+    ///
+    /// The model pads the buffered tail with `0x80`, zeros and the encoded
+    /// length, then expects every resulting block to have been emitted.
+    fn check_padding(&mut self, out: &[u8], suffix: &[u8]) {
+        let bs = self.inner.size();
+        let mut expected: Vec<u8> = self.model.drain(..).collect();
+        expected.push(0x80);
+        while expected.len() % bs != bs - suffix.len() {
+            expected.push(0);
+        }
+        expected.extend_from_slice(suffix);
+        assert_eq!(out, &expected[..], "padding blocks diverge from model");
+        self.check_position();
+    }
+
+    /// Assert that the cursor position matches the model's buffered length.
+    fn check_position(&self) {
+        assert_eq!(
+            self.inner.position(),
+            self.model.len(),
+            "cursor position diverges from model"
+        );
+    }
+}
+
+/// Strategy generating an input message split into chunks at random
+/// boundaries, for feeding into the buffer one chunk at a time.
+pub fn arb_input_chunks(
+    max_chunks: usize,
+    max_chunk_len: usize,
+) -> impl Strategy<Value = Vec<Vec<u8>>> {
+    prop_vec(prop_vec(any::<u8>(), 0..=max_chunk_len), 0..=max_chunks)
+}
@@ -0,0 +1,86 @@
+//! Property tests driving [`CheckedBuffer`] with randomly chunked inputs,
+//! exercising the reference model against every buffering operation so
+//! chunk-boundary bugs are caught regardless of split points.
+
+#![cfg(feature = "test-utils")]
+
+use block_buffer::generic_array::typenum::{U16, U8};
+use block_buffer::test_utils::{arb_input_chunks, CheckedBuffer};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn input_block_matches_model(chunks in arb_input_chunks(8, 24)) {
+        let mut buf = CheckedBuffer::<U8>::default();
+        for chunk in &chunks {
+            buf.input_block(chunk);
+        }
+    }
+
+    #[test]
+    fn input_blocks_matches_model(chunks in arb_input_chunks(8, 24)) {
+        let mut buf = CheckedBuffer::<U16>::default();
+        for chunk in &chunks {
+            buf.input_blocks(chunk);
+        }
+    }
+
+    #[test]
+    fn input_lazy_matches_model(chunks in arb_input_chunks(8, 24)) {
+        let mut buf = CheckedBuffer::<U8>::default();
+        for chunk in &chunks {
+            buf.input_lazy(chunk);
+        }
+    }
+
+    #[test]
+    fn mixed_inputs_and_padding_match_model(
+        chunks in arb_input_chunks(8, 24),
+        selectors in proptest::collection::vec(0u8..2, 8),
+        data_len in any::<u64>(),
+    ) {
+        let mut buf = CheckedBuffer::<U16>::default();
+        for (chunk, selector) in chunks.iter().zip(selectors) {
+            match selector {
+                0 => buf.input_block(chunk),
+                _ => buf.input_blocks(chunk),
+            }
+        }
+
+        buf.len64_padding_be(data_len);
+        prop_assert_eq!(buf.position(), 0);
+    }
+
+    #[test]
+    fn len64_padding_le_matches_model(
+        chunks in arb_input_chunks(4, 24),
+        data_len in any::<u64>(),
+    ) {
+        let mut buf = CheckedBuffer::<U8>::default();
+        for chunk in &chunks {
+            buf.input_block(chunk);
+        }
+
+        buf.len64_padding_le(data_len);
+        prop_assert_eq!(buf.position(), 0);
+    }
+
+    #[test]
+    fn len128_padding_be_matches_model(
+        chunks in arb_input_chunks(4, 24),
+        data_len in any::<u128>(),
+    ) {
+        let mut buf = CheckedBuffer::<U16>::default();
+        for chunk in &chunks {
+            buf.input_block(chunk);
+        }
+
+        buf.len128_padding_be(data_len);
+        prop_assert_eq!(buf.position(), 0);
+
+        // the buffer is reusable after padding
+        buf.reset();
+        buf.input_block(&[0x42]);
+        prop_assert_eq!(buf.position(), 1);
+    }
+}
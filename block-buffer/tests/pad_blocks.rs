@@ -0,0 +1,62 @@
+//! Tests for terminating padding via `pad_blocks_with`, in particular the
+//! block-aligned cases where the scheme does (or does not) add an extra
+//! padding block.
+
+#![cfg(feature = "block-padding")]
+
+use block_buffer::block_padding::{NoPadding, Pkcs7};
+use block_buffer::generic_array::typenum::U8;
+use block_buffer::BlockBuffer;
+
+#[test]
+fn pkcs7_aligned_message_gets_termination_block() {
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1; 16], |b| out.extend_from_slice(b));
+    buf.pad_blocks_with::<Pkcs7>(|b| out.extend_from_slice(b))
+        .unwrap();
+    assert_eq!(buf.position(), 0);
+    assert_eq!(out, [[1; 8], [1; 8], [8; 8]].concat());
+}
+
+#[test]
+fn pkcs7_partial_message_pads_final_block() {
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1; 11], |b| out.extend_from_slice(b));
+    buf.pad_blocks_with::<Pkcs7>(|b| out.extend_from_slice(b))
+        .unwrap();
+    assert_eq!(out, [[1; 8], [1, 1, 1, 5, 5, 5, 5, 5]].concat());
+}
+
+#[test]
+fn no_padding_aligned_message_adds_no_block() {
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1; 16], |b| out.extend_from_slice(b));
+    buf.pad_blocks_with::<NoPadding>(|b| out.extend_from_slice(b))
+        .unwrap();
+    assert_eq!(buf.position(), 0);
+    assert_eq!(out, [1; 16]);
+
+    // an empty message likewise emits nothing
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut called = false;
+    buf.pad_blocks_with::<NoPadding>(|_| called = true).unwrap();
+    assert!(!called);
+
+    // a block retained by `input_lazy` is flushed exactly once
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_lazy(&[2; 8], |b| out.extend_from_slice(b));
+    buf.pad_blocks_with::<NoPadding>(|b| out.extend_from_slice(b))
+        .unwrap();
+    assert_eq!(out, [2; 8]);
+}
+
+#[test]
+fn no_padding_rejects_partial_message() {
+    let mut buf = BlockBuffer::<U8>::default();
+    buf.input_block(&[1; 3], |_| ());
+    assert!(buf.pad_blocks_with::<NoPadding>(|_| ()).is_err());
+}
@@ -0,0 +1,88 @@
+//! Shared tests for the length-padding helpers, in particular the case
+//! where the length suffix does not fit in the current block and padding
+//! overflows into a second block.
+
+use block_buffer::generic_array::typenum::{U16, U8};
+use block_buffer::BlockBuffer;
+
+#[test]
+fn len64_padding_be_overflow() {
+    // 4 message bytes + 0x80 leave only 3 free bytes: the 8-byte length
+    // must go into a second block
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1, 2, 3, 4], |b| out.extend_from_slice(b));
+    buf.len64_padding_be(0x0102_0304_0506_0708, |b| out.extend_from_slice(b));
+    assert_eq!(buf.position(), 0);
+    assert_eq!(
+        out,
+        [
+            [1, 2, 3, 4, 0x80, 0, 0, 0],
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn len64_padding_le_overflow() {
+    let mut buf = BlockBuffer::<U8>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1, 2, 3, 4], |b| out.extend_from_slice(b));
+    buf.len64_padding_le(0x0102_0304_0506_0708, |b| out.extend_from_slice(b));
+    assert_eq!(buf.position(), 0);
+    assert_eq!(
+        out,
+        [
+            [1, 2, 3, 4, 0x80, 0, 0, 0],
+            [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn len128_padding_be_overflow() {
+    let msg: Vec<u8> = (1..=10).collect();
+    let mut buf = BlockBuffer::<U16>::default();
+    let mut out = Vec::new();
+    buf.input_block(&msg, |b| out.extend_from_slice(b));
+    buf.len128_padding_be(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10, |b| {
+        out.extend_from_slice(b)
+    });
+    assert_eq!(buf.position(), 0);
+
+    let mut expected = msg;
+    expected.push(0x80);
+    expected.extend_from_slice(&[0; 5]);
+    expected.extend_from_slice(&(1..=16).collect::<Vec<u8>>());
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn len128_padding_le_overflow() {
+    let msg: Vec<u8> = (1..=10).collect();
+    let mut buf = BlockBuffer::<U16>::default();
+    let mut out = Vec::new();
+    buf.input_block(&msg, |b| out.extend_from_slice(b));
+    buf.len128_padding_le(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10, |b| {
+        out.extend_from_slice(b)
+    });
+    assert_eq!(buf.position(), 0);
+
+    let mut expected = msg;
+    expected.push(0x80);
+    expected.extend_from_slice(&[0; 5]);
+    expected.extend_from_slice(&(1..=16).rev().collect::<Vec<u8>>());
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn len64_padding_fits_in_final_block() {
+    let mut buf = BlockBuffer::<U16>::default();
+    let mut out = Vec::new();
+    buf.input_block(&[1, 2, 3], |b| out.extend_from_slice(b));
+    buf.len64_padding_be(24, |b| out.extend_from_slice(b));
+    assert_eq!(buf.position(), 0);
+    assert_eq!(out, [1, 2, 3, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24]);
+}
@@ -6,13 +6,60 @@
 #![no_std]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
 
+use core::fmt;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "test-utils")]
+extern crate alloc;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 /// Error for indicating failed padding operation
-#[derive(Clone, Copy, Debug)]
-pub struct PadError;
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PadError {
+    /// Block or buffer is too small to fit the padded message
+    BlockTooSmall,
+    /// Message or block length is outside of the range supported
+    /// by the scheme
+    LengthOutOfRange,
+}
+
+impl fmt::Display for PadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PadError::BlockTooSmall => f.write_str("block too small for padding"),
+            PadError::LengthOutOfRange => f.write_str("length out of range for padding scheme"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PadError {}
 
 /// Error for indicating failed unpadding operation
-#[derive(Clone, Copy, Debug)]
-pub struct UnpadError;
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnpadError {
+    /// A padding byte has an invalid value
+    InvalidPadByte,
+    /// Decoded pad length is zero, exceeds the message length, or the
+    /// message length is invalid for the scheme
+    LengthOutOfRange,
+}
+
+impl fmt::Display for UnpadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnpadError::InvalidPadByte => f.write_str("invalid padding byte"),
+            UnpadError::LengthOutOfRange => f.write_str("padding length out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnpadError {}
 
 /// Trait for padding messages divided into blocks
 pub trait Padding {
@@ -34,15 +81,72 @@ pub trait Padding {
     fn pad(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
         let bs = block_size * (pos / block_size);
         if buf.len() < bs || buf.len() - bs < block_size {
-            Err(PadError)?
+            Err(PadError::BlockTooSmall)?
+        }
+        Self::pad_block(&mut buf[bs..bs + block_size], pos - bs)?;
+        Ok(&mut buf[..bs + block_size])
+    }
+
+    /// Pads message with length `pos` in the provided buffer, always
+    /// terminating the message with at least one byte of padding.
+    ///
+    /// Unlike [`Padding::pad`], which schemes like [`ZeroPadding`] override
+    /// to return block-aligned messages unchanged, this entry point emits a
+    /// full extra block of padding when the message is block-aligned, as
+    /// required by specifications where the padding marks the end of the
+    /// message. The output spans one block more than the message when
+    /// `pos % block_size == 0`, and the same buffer space requirements as
+    /// [`Padding::pad`] apply.
+    fn pad_blocks(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        let bs = block_size * (pos / block_size);
+        if buf.len() < bs || buf.len() - bs < block_size {
+            Err(PadError::BlockTooSmall)?
         }
         Self::pad_block(&mut buf[bs..bs + block_size], pos - bs)?;
         Ok(&mut buf[..bs + block_size])
     }
 
+    /// Copy the message tail `tail` into `block` and pad the rest of the
+    /// block, leaving the source data untouched.
+    ///
+    /// This allows padding without first copying the tail into a mutable
+    /// buffer, e.g. for pipelines which keep the plaintext immutable.
+    /// `tail` must be shorter than `block` (or of equal length for schemes
+    /// which allow padding a full block); otherwise `PadError` is returned.
+    fn pad_into(tail: &[u8], block: &mut [u8]) -> Result<(), PadError> {
+        if tail.len() > block.len() {
+            Err(PadError::BlockTooSmall)?
+        }
+        block[..tail.len()].copy_from_slice(tail);
+        Self::pad_block(block, tail.len())
+    }
+
     /// Unpad given `data` by truncating it according to the used padding.
     /// In case of the malformed padding will return `UnpadError`
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError>;
+
+    /// Unpad a contiguous multi-block message, e.g. a buffer produced by
+    /// CBC decryption, validating that its length is a multiple of
+    /// `block_size` before stripping the padding from the final block.
+    ///
+    /// ```
+    /// use block_padding::{Padding, Pkcs7};
+    ///
+    /// let padded = b"0123456789ab\x04\x04\x04\x04";
+    /// assert_eq!(Pkcs7::unpad_blocks(padded, 8).unwrap(), b"0123456789ab");
+    /// // length is not a multiple of the block size
+    /// assert!(Pkcs7::unpad_blocks(&padded[..15], 8).is_err());
+    /// // corrupted pad byte
+    /// assert!(Pkcs7::unpad_blocks(b"0123456789ab\x04\x03\x04\x04", 8).is_err());
+    /// // an empty message carries no padding
+    /// assert!(Pkcs7::unpad_blocks(b"", 8).is_err());
+    /// ```
+    fn unpad_blocks(data: &[u8], block_size: usize) -> Result<&[u8], UnpadError> {
+        if block_size == 0 || !data.len().is_multiple_of(block_size) {
+            Err(UnpadError::LengthOutOfRange)?
+        }
+        Self::unpad(data)
+    }
 }
 
 /// Pad block with zeros.
@@ -74,10 +178,28 @@ pub trait Padding {
 #[derive(Clone, Copy, Debug)]
 pub enum ZeroPadding {}
 
+impl ZeroPadding {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// Block-aligned messages are returned unchanged, so this is zero for
+    /// them. `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        (block_size - msg_len % block_size) % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
 impl Padding for ZeroPadding {
     fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
         if pos > block.len() {
-            Err(PadError)?
+            Err(PadError::BlockTooSmall)?
         }
         set(&mut block[pos..], 0);
         Ok(())
@@ -90,7 +212,7 @@ impl Padding for ZeroPadding {
             let bs = block_size * (pos / block_size);
             let be = bs + block_size;
             if buf.len() < be {
-                Err(PadError)?
+                Err(PadError::BlockTooSmall)?
             }
             Self::pad_block(&mut buf[bs..be], pos - bs)?;
             Ok(&mut buf[..be])
@@ -98,6 +220,9 @@ impl Padding for ZeroPadding {
     }
 
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
+        if data.is_empty() {
+            return Ok(data);
+        }
         let mut n = data.len() - 1;
         while n != 0 {
             if data[n] != 0 {
@@ -151,13 +276,30 @@ impl Padding for ZeroPadding {
 #[derive(Clone, Copy, Debug)]
 pub enum Pkcs7 {}
 
+impl Pkcs7 {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        block_size - msg_len % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
 impl Padding for Pkcs7 {
     fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
         if block.len() > 255 {
-            Err(PadError)?
+            Err(PadError::LengthOutOfRange)?
         }
         if pos >= block.len() {
-            Err(PadError)?
+            Err(PadError::BlockTooSmall)?
         }
         let n = block.len() - pos;
         set(&mut block[pos..], n as u8);
@@ -166,16 +308,16 @@ impl Padding for Pkcs7 {
 
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
         if data.is_empty() {
-            Err(UnpadError)?
+            Err(UnpadError::LengthOutOfRange)?
         }
         let l = data.len();
         let n = data[l - 1];
         if n == 0 || n as usize > l {
-            Err(UnpadError)?
+            Err(UnpadError::LengthOutOfRange)?
         }
         for v in &data[l - n as usize..l - 1] {
             if *v != n {
-                Err(UnpadError)?
+                Err(UnpadError::InvalidPadByte)?
             }
         }
         Ok(&data[..l - n as usize])
@@ -211,6 +353,11 @@ impl Padding for Pkcs7 {
 /// # let buffer = [0xff; 16];
 /// assert!(AnsiX923::unpad(&buffer).is_err());
 /// ```
+/// ```
+/// # use block_padding::{AnsiX923, Padding};
+/// // fill bytes must be zero
+/// assert!(AnsiX923::unpad(b"test\x01\x00\x00\x04").is_err());
+/// ```
 ///
 /// In addition to conditions stated in the `Padding` trait documentation,
 /// `pad_block` will return `PadError` if `block.len() > 255`, and in case of
@@ -218,13 +365,30 @@ impl Padding for Pkcs7 {
 #[derive(Clone, Copy, Debug)]
 pub enum AnsiX923 {}
 
+impl AnsiX923 {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        block_size - msg_len % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
 impl Padding for AnsiX923 {
     fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
         if block.len() > 255 {
-            Err(PadError)?
+            Err(PadError::LengthOutOfRange)?
         }
         if pos >= block.len() {
-            Err(PadError)?
+            Err(PadError::BlockTooSmall)?
         }
         let bs = block.len();
         set(&mut block[pos..bs - 1], 0);
@@ -234,22 +398,117 @@ impl Padding for AnsiX923 {
 
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
         if data.is_empty() {
-            Err(UnpadError)?
+            Err(UnpadError::LengthOutOfRange)?
         }
         let l = data.len();
         let n = data[l - 1] as usize;
         if n == 0 || n > l {
-            return Err(UnpadError);
+            return Err(UnpadError::LengthOutOfRange);
         }
         for v in &data[l - n..l - 1] {
             if *v != 0 {
-                Err(UnpadError)?
+                Err(UnpadError::InvalidPadByte)?
             }
         }
         Ok(&data[..l - n])
     }
 }
 
+/// Pad block with random bytes except the last byte which will be set to
+/// the number of bytes added, as specified in ISO 10126.
+///
+/// Since `unpad` never inspects the fill bytes, any fill value produces a
+/// valid padding. The [`Padding`] methods use zero fill; to fill with random
+/// bytes as the specification intends, enable the `rand_core` feature and use
+/// [`Iso10126::pad_block_rng`] with the RNG of your choice.
+///
+/// ```
+/// use block_padding::{Iso10126, Padding};
+///
+/// let msg = b"test";
+/// let n = msg.len();
+/// let mut buffer = [0xff; 16];
+/// buffer[..n].copy_from_slice(msg);
+/// let padded_msg = Iso10126::pad(&mut buffer, n, 8).unwrap();
+/// assert_eq!(padded_msg, b"test\x00\x00\x00\x04");
+/// assert_eq!(Iso10126::unpad(&padded_msg).unwrap(), msg);
+/// ```
+/// ```
+/// # use block_padding::{Iso10126, Padding};
+/// // fill bytes are not inspected during unpadding
+/// assert_eq!(Iso10126::unpad(b"test\xde\xad\xbe\x04").unwrap(), b"test");
+/// ```
+/// ```
+/// # use block_padding::{Iso10126, Padding};
+/// # let buffer = [0xff; 16];
+/// assert!(Iso10126::unpad(&buffer).is_err());
+/// ```
+///
+/// In addition to conditions stated in the `Padding` trait documentation,
+/// `pad_block` will return `PadError` if `block.len() > 255`, and in case of
+/// `pad` if `block_size > 255`.
+#[derive(Clone, Copy, Debug)]
+pub enum Iso10126 {}
+
+impl Iso10126 {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        block_size - msg_len % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+
+    /// Pad `block` filled with data up to `pos`, filling with random bytes
+    /// drawn from `rng`.
+    ///
+    /// Follows the same contract as [`Padding::pad_block`].
+    #[cfg(feature = "rand_core")]
+    pub fn pad_block_rng<R: rand_core::RngCore>(
+        block: &mut [u8],
+        pos: usize,
+        rng: &mut R,
+    ) -> Result<(), PadError> {
+        if block.len() > 255 {
+            Err(PadError::LengthOutOfRange)?
+        }
+        if pos >= block.len() {
+            Err(PadError::BlockTooSmall)?
+        }
+        let bs = block.len();
+        rng.fill_bytes(&mut block[pos..bs - 1]);
+        block[bs - 1] = (bs - pos) as u8;
+        Ok(())
+    }
+}
+
+impl Padding for Iso10126 {
+    fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        // Fill bytes are arbitrary per the specification, so the non-RNG
+        // path reuses the zero-filled ANSI X9.23 encoding
+        AnsiX923::pad_block(block, pos)
+    }
+
+    fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
+        if data.is_empty() {
+            Err(UnpadError::LengthOutOfRange)?
+        }
+        let l = data.len();
+        let n = data[l - 1] as usize;
+        if n == 0 || n > l {
+            return Err(UnpadError::LengthOutOfRange);
+        }
+        Ok(&data[..l - n])
+    }
+}
+
 /// Pad block with byte sequence `\x80 00...00 00`.
 ///
 /// ```
@@ -276,10 +535,27 @@ impl Padding for AnsiX923 {
 #[derive(Clone, Copy, Debug)]
 pub enum Iso7816 {}
 
+impl Iso7816 {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        block_size - msg_len % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
 impl Padding for Iso7816 {
     fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
         if pos >= block.len() {
-            Err(PadError)?
+            Err(PadError::BlockTooSmall)?
         }
         block[pos] = 0x80;
         set(&mut block[pos + 1..], 0);
@@ -288,7 +564,7 @@ impl Padding for Iso7816 {
 
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
         if data.is_empty() {
-            Err(UnpadError)?
+            Err(UnpadError::LengthOutOfRange)?
         }
         let mut n = data.len() - 1;
         while n != 0 {
@@ -298,12 +574,282 @@ impl Padding for Iso7816 {
             n -= 1;
         }
         if data[n] != 0x80 {
-            Err(UnpadError)?
+            Err(UnpadError::InvalidPadByte)?
         }
         Ok(&data[..n])
     }
 }
 
+/// Pad block with the binary complement of the last message bit
+/// (trailing-bit-complement padding, described in ISO/IEC 9797-1).
+///
+/// Every pad byte is `0x00` if the last message bit is one and `0xFF` if it
+/// is zero, which makes the message/padding boundary unambiguous: the last
+/// message byte can never equal the pad byte. Messages ending in the middle
+/// of a block always receive at least one byte of padding; block-aligned
+/// messages receive a full extra block. For an empty message the pad byte is
+/// `0xFF` (the last message bit is treated as zero).
+///
+/// ```
+/// use block_padding::{Tbc, Padding};
+///
+/// let msg = b"test";
+/// let n = msg.len();
+/// let mut buffer = [0x00; 16];
+/// buffer[..n].copy_from_slice(msg);
+/// // the last bit of `t` (0x74) is zero, so the pad byte is 0xFF
+/// let padded_msg = Tbc::pad(&mut buffer, n, 8).unwrap();
+/// assert_eq!(padded_msg, b"test\xff\xff\xff\xff");
+/// assert_eq!(Tbc::unpad(&padded_msg).unwrap(), msg);
+/// ```
+/// ```
+/// # use block_padding::{Tbc, Padding};
+/// let msg = b"ab\x01";
+/// let n = msg.len();
+/// let mut buffer = [0xff; 8];
+/// buffer[..n].copy_from_slice(msg);
+/// let padded_msg = Tbc::pad(&mut buffer, n, 4).unwrap();
+/// assert_eq!(padded_msg, b"ab\x01\x00");
+/// assert_eq!(Tbc::unpad(&padded_msg).unwrap(), msg);
+/// ```
+/// ```
+/// # use block_padding::{Tbc, Padding};
+/// // block-aligned messages get a full block of padding
+/// let msg = b"test";
+/// let n = msg.len();
+/// let mut buffer = [0x00; 8];
+/// buffer[..n].copy_from_slice(msg);
+/// let padded_msg = Tbc::pad(&mut buffer, n, 4).unwrap();
+/// assert_eq!(padded_msg, b"test\xff\xff\xff\xff");
+/// assert_eq!(Tbc::unpad(&padded_msg).unwrap(), msg);
+/// ```
+/// ```
+/// # use block_padding::{Tbc, Padding};
+/// // the last message bit must be the complement of the pad bit
+/// assert!(Tbc::unpad(b"ab\x01\xff\xff").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub enum Tbc {}
+
+impl Tbc {
+    /// Pad byte complementing the last bit of the provided byte
+    fn pad_byte(last_byte: u8) -> u8 {
+        if last_byte & 1 == 0 {
+            0xFF
+        } else {
+            0x00
+        }
+    }
+}
+
+impl Tbc {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        block_size - msg_len % block_size
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
+impl Padding for Tbc {
+    fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        // the last message bit is not available for an empty block
+        if pos == 0 {
+            Err(PadError::LengthOutOfRange)?
+        }
+        if pos >= block.len() {
+            Err(PadError::BlockTooSmall)?
+        }
+        let b = Self::pad_byte(block[pos - 1]);
+        set(&mut block[pos..], b);
+        Ok(())
+    }
+
+    fn pad(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        let bs = block_size * (pos / block_size);
+        if buf.len() < bs || buf.len() - bs < block_size {
+            Err(PadError::BlockTooSmall)?
+        }
+        let b = if pos == 0 {
+            0xFF
+        } else {
+            Self::pad_byte(buf[pos - 1])
+        };
+        set(&mut buf[pos..bs + block_size], b);
+        Ok(&mut buf[..bs + block_size])
+    }
+
+    fn pad_blocks(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        // `pad` already always terminates the message, including the
+        // block-aligned case
+        Self::pad(buf, pos, block_size)
+    }
+
+    fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
+        if data.is_empty() {
+            Err(UnpadError::LengthOutOfRange)?
+        }
+        let l = data.len();
+        let v = data[l - 1];
+        if v != 0x00 && v != 0xFF {
+            Err(UnpadError::InvalidPadByte)?
+        }
+        let mut n = l - 1;
+        while n != 0 {
+            if data[n - 1] != v {
+                break;
+            }
+            n -= 1;
+        }
+        if n == 0 {
+            // empty message: only the all-0xFF encoding is valid
+            return if v == 0xFF { Ok(&[]) } else { Err(UnpadError::InvalidPadByte) };
+        }
+        // the last message bit must be the complement of the pad bit
+        if data[n - 1] & 1 == v & 1 {
+            Err(UnpadError::InvalidPadByte)?
+        }
+        Ok(&data[..n])
+    }
+}
+
+/// Pad block with zeros except the final two bytes which encode the number
+/// of bytes added as a 16-bit big-endian integer.
+///
+/// Generalization of the ANSI X9.23 encoding for the wide blocks used by
+/// wide-block cipher modes, where the single-byte length encoding of
+/// schemes like [`Pkcs7`] caps the block size at 255 bytes. Valid for block
+/// sizes from 2 to 65535 bytes; `pad_block` returns
+/// `PadError::LengthOutOfRange` outside of that range instead of silently
+/// truncating the length.
+///
+/// If fewer than two bytes remain in the final block, the length encoding
+/// doesn't fit and `pad` spills the padding into one additional block, so
+/// `buf` may need up to `block_size + 1` bytes after the message end.
+///
+/// ```
+/// use block_padding::{WideX923, Padding};
+///
+/// let msg = b"test";
+/// let n = msg.len();
+/// let mut buffer = [0xff; 16];
+/// buffer[..n].copy_from_slice(msg);
+/// let padded_msg = WideX923::pad(&mut buffer, n, 8).unwrap();
+/// assert_eq!(padded_msg, b"test\x00\x00\x00\x04");
+/// assert_eq!(WideX923::unpad(&padded_msg).unwrap(), msg);
+/// ```
+/// ```
+/// # use block_padding::{WideX923, Padding};
+/// // a 300-byte wide block is beyond Pkcs7 but fine here
+/// let mut buffer = [0u8; 300];
+/// buffer[..4].copy_from_slice(b"test");
+/// let padded_msg = WideX923::pad(&mut buffer, 4, 300).unwrap();
+/// assert_eq!(padded_msg.len(), 300);
+/// assert_eq!(&padded_msg[298..], &[0x01, 0x28]); // 296 pad bytes
+/// assert_eq!(WideX923::unpad(&padded_msg).unwrap(), b"test");
+/// ```
+/// ```
+/// # use block_padding::{WideX923, Padding};
+/// # let buffer = [0xff; 16];
+/// assert!(WideX923::unpad(&buffer).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub enum WideX923 {}
+
+impl WideX923 {
+    /// Number of padding bytes added by [`Padding::pad`] to a message of
+    /// length `msg_len` for the given block size.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn pad_len(msg_len: usize, block_size: usize) -> usize {
+        let n = block_size - msg_len % block_size;
+        // a single free byte can't hold the two-byte length encoding,
+        // so the padding spills into an additional block
+        if n < 2 {
+            n + block_size
+        } else {
+            n
+        }
+    }
+
+    /// Total length of a message of length `msg_len` after padding.
+    ///
+    /// `block_size` must be non-zero.
+    pub const fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        msg_len + Self::pad_len(msg_len, block_size)
+    }
+}
+
+impl Padding for WideX923 {
+    fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        if block.len() < 2 || block.len() > 65535 {
+            Err(PadError::LengthOutOfRange)?
+        }
+        // two bytes are needed for the length encoding
+        if pos + 2 > block.len() {
+            Err(PadError::BlockTooSmall)?
+        }
+        let bs = block.len();
+        let n = (bs - pos) as u16;
+        set(&mut block[pos..bs - 2], 0);
+        block[bs - 2..].copy_from_slice(&n.to_be_bytes());
+        Ok(())
+    }
+
+    fn pad(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        if !(2..=65535).contains(&block_size) {
+            Err(PadError::LengthOutOfRange)?
+        }
+        // pad up to the next block boundary; if fewer than two bytes remain
+        // in the current block, the length encoding spills into an extra one
+        let mut end = block_size * (pos / block_size) + block_size;
+        if end - pos < 2 {
+            end += block_size;
+        }
+        if end - pos > 65535 {
+            Err(PadError::LengthOutOfRange)?
+        }
+        if buf.len() < end {
+            Err(PadError::BlockTooSmall)?
+        }
+        let n = (end - pos) as u16;
+        set(&mut buf[pos..end - 2], 0);
+        buf[end - 2..end].copy_from_slice(&n.to_be_bytes());
+        Ok(&mut buf[..end])
+    }
+
+    fn pad_blocks(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        // `pad` already always terminates the message, including the
+        // block-aligned case
+        Self::pad(buf, pos, block_size)
+    }
+
+    fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
+        let l = data.len();
+        if l < 2 {
+            Err(UnpadError::LengthOutOfRange)?
+        }
+        let n = u16::from_be_bytes([data[l - 2], data[l - 1]]) as usize;
+        if n < 2 || n > l {
+            Err(UnpadError::LengthOutOfRange)?
+        }
+        for v in &data[l - n..l - 2] {
+            if *v != 0 {
+                Err(UnpadError::InvalidPadByte)?
+            }
+        }
+        Ok(&data[..l - n])
+    }
+}
+
 /// Don't pad the data. Useful for key wrapping. Padding will fail if the data cannot be
 /// fitted into blocks without padding.
 ///
@@ -328,29 +874,318 @@ impl Padding for Iso7816 {
 /// assert_eq!(padded_msg, b"test");
 /// assert_eq!(NoPadding::unpad(&padded_msg).unwrap(), msg);
 /// ```
+///
+/// To guard against final partial blocks being silently dropped by a cipher
+/// mode, `pad` rejects messages which are not already block-aligned, and
+/// [`Padding::unpad_blocks`] performs the same check on the way out
+/// (`unpad` alone cannot, as it doesn't know the block size):
+///
+/// ```
+/// # use block_padding::{NoPadding, Padding};
+/// let mut buffer = [0xff; 16];
+/// buffer[..5].copy_from_slice(b"test!");
+/// assert!(NoPadding::pad(&mut buffer, 5, 4).is_err());
+/// assert!(NoPadding::unpad_blocks(b"test!", 4).is_err());
+/// assert_eq!(NoPadding::unpad_blocks(b"testtest", 4).unwrap(), b"testtest");
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub enum NoPadding {}
 
+impl NoPadding {
+    /// Number of padding bytes added by [`Padding::pad`]: always zero.
+    pub const fn pad_len(_msg_len: usize, _block_size: usize) -> usize {
+        0
+    }
+
+    /// Total length of a message of length `msg_len` after padding:
+    /// always `msg_len`.
+    pub const fn padded_len(msg_len: usize, _block_size: usize) -> usize {
+        msg_len
+    }
+}
+
 impl Padding for NoPadding {
     fn pad_block(block: &mut [u8], pos: usize) -> Result<(), PadError> {
         if pos % block.len() != 0 {
-            Err(PadError)?
+            Err(PadError::LengthOutOfRange)?
         }
         Ok(())
     }
 
     fn pad(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
         if pos % block_size != 0 {
-            Err(PadError)?
+            Err(PadError::LengthOutOfRange)?
         }
         Ok(&mut buf[..pos])
     }
 
+    fn pad_blocks(buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        // never emits padding, so there is no termination block to add
+        Self::pad(buf, pos, block_size)
+    }
+
     fn unpad(data: &[u8]) -> Result<&[u8], UnpadError> {
         Ok(data)
     }
 }
 
+/// Padding scheme selected at runtime.
+///
+/// The [`Padding`] trait is only usable as a compile-time type parameter,
+/// which doesn't work for protocol stacks that negotiate the padding scheme
+/// at runtime. Each variant of this enum dispatches to the corresponding
+/// [`Padding`] implementation.
+///
+/// ```
+/// use block_padding::PaddingScheme;
+///
+/// let msg = b"test";
+/// let n = msg.len();
+/// let mut buffer = [0xff; 16];
+/// buffer[..n].copy_from_slice(msg);
+/// let scheme = PaddingScheme::Pkcs7;
+/// let padded_msg = scheme.pad(&mut buffer, n, 8).unwrap();
+/// assert_eq!(padded_msg, b"test\x04\x04\x04\x04");
+/// assert_eq!(scheme.unpad(&padded_msg).unwrap(), msg);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaddingScheme {
+    /// [`ZeroPadding`]
+    ZeroPadding,
+    /// [`Pkcs7`]
+    Pkcs7,
+    /// [`AnsiX923`]
+    AnsiX923,
+    /// [`Iso10126`]
+    Iso10126,
+    /// [`Iso7816`]
+    Iso7816,
+    /// [`Tbc`]
+    Tbc,
+    /// [`WideX923`]
+    WideX923,
+    /// [`NoPadding`]
+    NoPadding,
+}
+
+impl PaddingScheme {
+    /// Pads `block` filled with data up to `pos` using the selected scheme.
+    ///
+    /// See [`Padding::pad_block`] for the detailed contract.
+    pub fn pad_block(self, block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        match self {
+            PaddingScheme::ZeroPadding => ZeroPadding::pad_block(block, pos),
+            PaddingScheme::Pkcs7 => Pkcs7::pad_block(block, pos),
+            PaddingScheme::AnsiX923 => AnsiX923::pad_block(block, pos),
+            PaddingScheme::Iso10126 => Iso10126::pad_block(block, pos),
+            PaddingScheme::Iso7816 => Iso7816::pad_block(block, pos),
+            PaddingScheme::Tbc => Tbc::pad_block(block, pos),
+            PaddingScheme::WideX923 => WideX923::pad_block(block, pos),
+            PaddingScheme::NoPadding => NoPadding::pad_block(block, pos),
+        }
+    }
+
+    /// Pads message with length `pos` in the provided buffer using the
+    /// selected scheme.
+    ///
+    /// See [`Padding::pad`] for the detailed contract.
+    pub fn pad(self, buf: &mut [u8], pos: usize, block_size: usize) -> Result<&mut [u8], PadError> {
+        match self {
+            PaddingScheme::ZeroPadding => ZeroPadding::pad(buf, pos, block_size),
+            PaddingScheme::Pkcs7 => Pkcs7::pad(buf, pos, block_size),
+            PaddingScheme::AnsiX923 => AnsiX923::pad(buf, pos, block_size),
+            PaddingScheme::Iso10126 => Iso10126::pad(buf, pos, block_size),
+            PaddingScheme::Iso7816 => Iso7816::pad(buf, pos, block_size),
+            PaddingScheme::Tbc => Tbc::pad(buf, pos, block_size),
+            PaddingScheme::WideX923 => WideX923::pad(buf, pos, block_size),
+            PaddingScheme::NoPadding => NoPadding::pad(buf, pos, block_size),
+        }
+    }
+
+    /// Copy the message tail `tail` into `block` and pad the rest of the
+    /// block using the selected scheme.
+    ///
+    /// See [`Padding::pad_into`] for the detailed contract.
+    pub fn pad_into(self, tail: &[u8], block: &mut [u8]) -> Result<(), PadError> {
+        match self {
+            PaddingScheme::ZeroPadding => ZeroPadding::pad_into(tail, block),
+            PaddingScheme::Pkcs7 => Pkcs7::pad_into(tail, block),
+            PaddingScheme::AnsiX923 => AnsiX923::pad_into(tail, block),
+            PaddingScheme::Iso10126 => Iso10126::pad_into(tail, block),
+            PaddingScheme::Iso7816 => Iso7816::pad_into(tail, block),
+            PaddingScheme::Tbc => Tbc::pad_into(tail, block),
+            PaddingScheme::WideX923 => WideX923::pad_into(tail, block),
+            PaddingScheme::NoPadding => NoPadding::pad_into(tail, block),
+        }
+    }
+
+    /// Unpad given `data` according to the selected scheme.
+    ///
+    /// See [`Padding::unpad`] for the detailed contract.
+    pub fn unpad(self, data: &[u8]) -> Result<&[u8], UnpadError> {
+        match self {
+            PaddingScheme::ZeroPadding => ZeroPadding::unpad(data),
+            PaddingScheme::Pkcs7 => Pkcs7::unpad(data),
+            PaddingScheme::AnsiX923 => AnsiX923::unpad(data),
+            PaddingScheme::Iso10126 => Iso10126::unpad(data),
+            PaddingScheme::Iso7816 => Iso7816::unpad(data),
+            PaddingScheme::Tbc => Tbc::unpad(data),
+            PaddingScheme::WideX923 => WideX923::unpad(data),
+            PaddingScheme::NoPadding => NoPadding::unpad(data),
+        }
+    }
+
+    /// Unpad a contiguous multi-block message using the selected scheme.
+    ///
+    /// See [`Padding::unpad_blocks`] for the detailed contract.
+    pub fn unpad_blocks(self, data: &[u8], block_size: usize) -> Result<&[u8], UnpadError> {
+        match self {
+            PaddingScheme::ZeroPadding => ZeroPadding::unpad_blocks(data, block_size),
+            PaddingScheme::Pkcs7 => Pkcs7::unpad_blocks(data, block_size),
+            PaddingScheme::AnsiX923 => AnsiX923::unpad_blocks(data, block_size),
+            PaddingScheme::Iso10126 => Iso10126::unpad_blocks(data, block_size),
+            PaddingScheme::Iso7816 => Iso7816::unpad_blocks(data, block_size),
+            PaddingScheme::Tbc => Tbc::unpad_blocks(data, block_size),
+            PaddingScheme::WideX923 => WideX923::unpad_blocks(data, block_size),
+            PaddingScheme::NoPadding => NoPadding::unpad_blocks(data, block_size),
+        }
+    }
+}
+
+/// Object-safe counterpart of the [`Padding`] trait.
+///
+/// The methods of [`Padding`] take no receiver, so the trait cannot be used
+/// as a trait object and paddings cannot be stored in runtime-configured
+/// cipher wrappers. This facade takes `&self` and is dyn-compatible:
+/// `&dyn DynPadding` and `Box<dyn DynPadding>` work, while the static
+/// [`Padding`] path remains zero-cost.
+///
+/// It is implemented by [`PaddingScheme`] and, for any static [`Padding`]
+/// implementation, by the zero-sized [`DynPad`] adapter:
+///
+/// ```
+/// use block_padding::{DynPad, DynPadding, PaddingScheme, Pkcs7};
+///
+/// let paddings: [&dyn DynPadding; 2] = [&PaddingScheme::Pkcs7, &DynPad::<Pkcs7>::default()];
+/// for padding in &paddings {
+///     let mut buffer = [0xff; 8];
+///     buffer[..4].copy_from_slice(b"test");
+///     padding.pad_block(&mut buffer, 4).unwrap();
+///     assert_eq!(&buffer, b"test\x04\x04\x04\x04");
+///     assert_eq!(padding.unpad(&buffer).unwrap(), b"test");
+/// }
+/// ```
+pub trait DynPadding {
+    /// Pads `block` filled with data up to `pos`.
+    ///
+    /// See [`Padding::pad_block`] for the detailed contract.
+    fn pad_block(&self, block: &mut [u8], pos: usize) -> Result<(), PadError>;
+
+    /// Pads message with length `pos` in the provided buffer.
+    ///
+    /// See [`Padding::pad`] for the detailed contract.
+    fn pad<'a>(
+        &self,
+        buf: &'a mut [u8],
+        pos: usize,
+        block_size: usize,
+    ) -> Result<&'a mut [u8], PadError>;
+
+    /// Copy the message tail `tail` into `block` and pad the rest of
+    /// the block.
+    ///
+    /// See [`Padding::pad_into`] for the detailed contract.
+    fn pad_into(&self, tail: &[u8], block: &mut [u8]) -> Result<(), PadError>;
+
+    /// Unpad given `data`.
+    ///
+    /// See [`Padding::unpad`] for the detailed contract.
+    fn unpad<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], UnpadError>;
+
+    /// Unpad a contiguous multi-block message.
+    ///
+    /// See [`Padding::unpad_blocks`] for the detailed contract.
+    fn unpad_blocks<'a>(&self, data: &'a [u8], block_size: usize)
+        -> Result<&'a [u8], UnpadError>;
+}
+
+impl DynPadding for PaddingScheme {
+    fn pad_block(&self, block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        PaddingScheme::pad_block(*self, block, pos)
+    }
+
+    fn pad<'a>(
+        &self,
+        buf: &'a mut [u8],
+        pos: usize,
+        block_size: usize,
+    ) -> Result<&'a mut [u8], PadError> {
+        PaddingScheme::pad(*self, buf, pos, block_size)
+    }
+
+    fn pad_into(&self, tail: &[u8], block: &mut [u8]) -> Result<(), PadError> {
+        PaddingScheme::pad_into(*self, tail, block)
+    }
+
+    fn unpad<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], UnpadError> {
+        PaddingScheme::unpad(*self, data)
+    }
+
+    fn unpad_blocks<'a>(
+        &self,
+        data: &'a [u8],
+        block_size: usize,
+    ) -> Result<&'a [u8], UnpadError> {
+        PaddingScheme::unpad_blocks(*self, data, block_size)
+    }
+}
+
+/// Zero-sized adapter exposing a static [`Padding`] implementation through
+/// the object-safe [`DynPadding`] trait.
+#[derive(Clone, Copy, Debug)]
+pub struct DynPad<P: Padding> {
+    _padding: core::marker::PhantomData<P>,
+}
+
+impl<P: Padding> Default for DynPad<P> {
+    fn default() -> Self {
+        Self {
+            _padding: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<P: Padding> DynPadding for DynPad<P> {
+    fn pad_block(&self, block: &mut [u8], pos: usize) -> Result<(), PadError> {
+        P::pad_block(block, pos)
+    }
+
+    fn pad<'a>(
+        &self,
+        buf: &'a mut [u8],
+        pos: usize,
+        block_size: usize,
+    ) -> Result<&'a mut [u8], PadError> {
+        P::pad(buf, pos, block_size)
+    }
+
+    fn pad_into(&self, tail: &[u8], block: &mut [u8]) -> Result<(), PadError> {
+        P::pad_into(tail, block)
+    }
+
+    fn unpad<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], UnpadError> {
+        P::unpad(data)
+    }
+
+    fn unpad_blocks<'a>(
+        &self,
+        data: &'a [u8],
+        block_size: usize,
+    ) -> Result<&'a [u8], UnpadError> {
+        P::unpad_blocks(data, block_size)
+    }
+}
+
 /// Sets all bytes in `dst` equal to `value`
 #[inline(always)]
 fn set(dst: &mut [u8], value: u8) {
@@ -0,0 +1,34 @@
+//! Uniform property coverage for all padding schemes in this crate.
+#![cfg(feature = "test-utils")]
+
+use block_padding::test_utils::{check_roundtrip, check_unpad_corruption};
+use block_padding::{AnsiX923, Iso10126, Iso7816, NoPadding, Pkcs7, Tbc, WideX923, ZeroPadding};
+
+#[test]
+fn roundtrip() {
+    for &bs in &[4usize, 8, 16, 64] {
+        check_roundtrip::<ZeroPadding>(bs, 3 * bs);
+        check_roundtrip::<Pkcs7>(bs, 3 * bs);
+        check_roundtrip::<AnsiX923>(bs, 3 * bs);
+        check_roundtrip::<Iso10126>(bs, 3 * bs);
+        check_roundtrip::<Iso7816>(bs, 3 * bs);
+        check_roundtrip::<Tbc>(bs, 3 * bs);
+        check_roundtrip::<WideX923>(bs, 3 * bs);
+    }
+    // wide-block scheme beyond the 255-byte limit of the others
+    check_roundtrip::<WideX923>(300, 650);
+}
+
+#[test]
+fn unpad_corruption() {
+    for &bs in &[4usize, 8, 16] {
+        check_unpad_corruption::<Pkcs7>(bs, 3 * bs);
+        check_unpad_corruption::<AnsiX923>(bs, 3 * bs);
+        check_unpad_corruption::<Iso10126>(bs, 3 * bs);
+        check_unpad_corruption::<Iso7816>(bs, 3 * bs);
+        check_unpad_corruption::<Tbc>(bs, 3 * bs);
+        check_unpad_corruption::<WideX923>(bs, 3 * bs);
+        check_unpad_corruption::<ZeroPadding>(bs, 3 * bs);
+        check_unpad_corruption::<NoPadding>(bs, 3 * bs);
+    }
+}
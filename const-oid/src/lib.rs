@@ -57,6 +57,12 @@ use core::{
     str::{FromStr, Split},
 };
 
+/// Type alias used to represent an "arc" (a.k.a. node) of an OID.
+///
+/// Arcs are 128-bit in order to losslessly represent large vendor and
+/// UUID-derived arcs, e.g. the 128-bit arcs found under `2.25`.
+pub type Arc = u128;
+
 /// Minimum number of arcs in an OID.
 ///
 /// This library will refuse to parse OIDs with fewer than this number of arcs.
@@ -81,10 +87,10 @@ pub const MAX_ARCS: usize = 12;
 const MAX_LOWER_ARCS: usize = MAX_ARCS - 2;
 
 /// Maximum value of the first arc in an OID
-const FIRST_ARC_MAX: u32 = 2;
+const FIRST_ARC_MAX: Arc = 2;
 
 /// Maximum value of the second arc in an OID
-const SECOND_ARC_MAX: u32 = 39;
+const SECOND_ARC_MAX: Arc = 39;
 
 /// Error type
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -135,8 +141,8 @@ impl ObjectIdentifier {
     ///
     /// For that reason this method is not recommended except for use in
     /// constants (where it will generate a compiler error instead).
-    /// To parse an OID from a `&[u32]` slice without panicking on error,
-    /// use [`TryFrom<&[u32]>`][1] instead.
+    /// To parse an OID from a `&[Arc]` slice without panicking on error,
+    /// use [`TryFrom<&[Arc]>`][1] instead.
     ///
     /// In order for an OID to be valid, it must meet the following criteria:
     ///
@@ -145,8 +151,8 @@ impl ObjectIdentifier {
     /// - The first arc MUST be within the range 0-2
     /// - The second arc MUST be within the range 0-39
     ///
-    /// [1]: ./struct.ObjectIdentifier.html#impl-TryFrom%3C%26%27_%20%5Bu32%5D%3E
-    pub const fn new(arcs: &[u32]) -> Self {
+    /// [1]: ./struct.ObjectIdentifier.html#impl-TryFrom%3C%26%27_%20%5BArc%5D%3E
+    pub const fn new(arcs: &[Arc]) -> Self {
         const_assert!(arcs.len() >= MIN_ARCS, "OID too short (minimum 3 arcs)");
         const_assert!(
             arcs.len() <= MAX_ARCS,
@@ -165,54 +171,15 @@ impl ObjectIdentifier {
             "invalid second arc (must be 0-39)"
         );
 
-        let root_arcs = RootArcs((first_arc * (SECOND_ARC_MAX + 1)) as u8 | second_arc as u8);
-
-        // TODO(tarcieri): use `const_mut_ref` when stable.
-        // See: <https://github.com/rust-lang/rust/issues/57349>
-        #[rustfmt::skip]
-        let lower_arcs = match arcs.len() {
-            3 => [
-                arcs[2], 0, 0, 0, 0,
-                0, 0, 0, 0, 0
-            ],
-            4 => [
-                arcs[2], arcs[3], 0, 0, 0,
-                0, 0, 0, 0, 0
-            ],
-            5 => [
-                arcs[2], arcs[3], arcs[4], 0, 0,
-                0, 0, 0, 0, 0
-            ],
-            6 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], 0,
-                0, 0, 0, 0, 0
-            ],
-            7 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                0, 0, 0, 0, 0,
-            ],
-            8 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                arcs[7], 0, 0, 0, 0,
-            ],
-            9 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                arcs[7], arcs[8], 0, 0, 0,
-            ],
-            10 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                arcs[7], arcs[8], arcs[9], 0, 0,
-            ],
-            11 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                arcs[7], arcs[8], arcs[9], arcs[10], 0,
-            ],
-            12 => [
-                arcs[2], arcs[3], arcs[4], arcs[5], arcs[6],
-                arcs[7], arcs[8], arcs[9], arcs[10], arcs[11],
-            ],
-            _ => [0u32; MAX_LOWER_ARCS], // Checks above prevent this case, but makes Miri happy
-        };
+        let root_arcs = RootArcs((first_arc * (SECOND_ARC_MAX + 1)) as u8 + second_arc as u8);
+
+        let mut lower_arcs = [0; MAX_LOWER_ARCS];
+        let mut index = 2;
+
+        while index < arcs.len() {
+            lower_arcs[index - 2] = arcs[index];
+            index += 1;
+        }
 
         // TODO(tarcieri): use `LowerArcs::new` when `const fn`-friendly
         Self {
@@ -224,8 +191,92 @@ impl ObjectIdentifier {
         }
     }
 
+    /// Parse an [`ObjectIdentifier`] from its dotted-decimal string
+    /// representation, e.g. `"1.2.840.113549.1.1.11"`.
+    ///
+    /// # Panics
+    ///
+    /// Like [`ObjectIdentifier::new`], this method panics on malformed
+    /// input in order to enable `const fn` usage (where it will generate a
+    /// compile error instead). To parse a string at runtime without
+    /// panicking, use the [`FromStr`] impl instead.
+    pub const fn parse(s: &str) -> Self {
+        let bytes = s.as_bytes();
+
+        let mut arcs = [0; MAX_ARCS];
+        let mut arc_count = 0;
+        let mut current: Arc = 0;
+        let mut digits = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let byte = bytes[i];
+
+            if byte == b'.' {
+                const_assert!(digits > 0, "malformed OID string (empty arc)");
+                const_assert!(
+                    arc_count < MAX_ARCS,
+                    "OID too long (internal limit reached)"
+                );
+                arcs[arc_count] = current;
+                arc_count += 1;
+                current = 0;
+                digits = 0;
+            } else {
+                const_assert!(
+                    byte >= b'0' && byte <= b'9',
+                    "malformed OID string (expected digit)"
+                );
+                current = current * 10 + (byte - b'0') as Arc;
+                digits += 1;
+            }
+
+            i += 1;
+        }
+
+        const_assert!(digits > 0, "malformed OID string (empty arc)");
+        const_assert!(
+            arc_count < MAX_ARCS,
+            "OID too long (internal limit reached)"
+        );
+        arcs[arc_count] = current;
+        arc_count += 1;
+
+        const_assert!(arc_count >= MIN_ARCS, "OID too short (minimum 3 arcs)");
+
+        let first_arc = arcs[0];
+        const_assert!(
+            first_arc <= FIRST_ARC_MAX,
+            "invalid first arc (must be 0-2)"
+        );
+
+        let second_arc = arcs[1];
+        const_assert!(
+            second_arc <= SECOND_ARC_MAX,
+            "invalid second arc (must be 0-39)"
+        );
+
+        let root_arcs = RootArcs((first_arc * (SECOND_ARC_MAX + 1)) as u8 + second_arc as u8);
+
+        let mut lower_arcs = [0; MAX_LOWER_ARCS];
+        let mut index = 2;
+
+        while index < arc_count {
+            lower_arcs[index - 2] = arcs[index];
+            index += 1;
+        }
+
+        Self {
+            root_arcs,
+            lower_arcs: LowerArcs {
+                length: (arc_count - 2) as u8,
+                arcs: lower_arcs,
+            },
+        }
+    }
+
     /// Return the arc with the given index, if it exists.
-    pub fn arc(&self, index: usize) -> Option<u32> {
+    pub fn arc(&self, index: usize) -> Option<Arc> {
         match index {
             0 => Some(self.root_arcs.first_arc()),
             1 => Some(self.root_arcs.second_arc()),
@@ -235,8 +286,8 @@ impl ObjectIdentifier {
 
     /// Iterate over the arcs (a.k.a. nodes) in an [`ObjectIdentifier`].
     ///
-    /// Returns [`Arcs`], an iterator over `u32` values representing the value
-    /// of each arc/node.
+    /// Returns [`Arcs`], an iterator over [`Arc`] values representing the
+    /// value of each arc/node.
     pub fn arcs(&self) -> Arcs {
         Arcs {
             oid: *self,
@@ -311,10 +362,10 @@ impl FromStr for ObjectIdentifier {
     }
 }
 
-impl TryFrom<&[u32]> for ObjectIdentifier {
+impl TryFrom<&[Arc]> for ObjectIdentifier {
     type Error = Error;
 
-    fn try_from(arcs: &[u32]) -> Result<Self> {
+    fn try_from(arcs: &[Arc]) -> Result<Self> {
         if arcs.len() < MIN_ARCS || arcs.len() > MAX_ARCS {
             return Err(Error);
         }
@@ -367,9 +418,9 @@ pub struct Arcs {
 }
 
 impl Iterator for Arcs {
-    type Item = u32;
+    type Item = Arc;
 
-    fn next(&mut self) -> Option<u32> {
+    fn next(&mut self) -> Option<Arc> {
         let arc = self.oid.arc(self.index)?;
         self.index = self.index.checked_add(1).unwrap();
         Some(arc)
@@ -385,24 +436,24 @@ struct RootArcs(u8);
 
 impl RootArcs {
     /// Create [`RootArcs`] from the first and second arc values represented
-    /// as `u32` integers.
-    fn new(first_arc: u32, second_arc: u32) -> Result<Self> {
+    /// as [`Arc`] integers.
+    fn new(first_arc: Arc, second_arc: Arc) -> Result<Self> {
         if first_arc > FIRST_ARC_MAX || second_arc > SECOND_ARC_MAX {
             return Err(Error);
         }
 
-        let byte = (first_arc * (SECOND_ARC_MAX + 1)) as u8 | second_arc as u8;
+        let byte = (first_arc * (SECOND_ARC_MAX + 1)) as u8 + second_arc as u8;
         Ok(Self(byte))
     }
 
     /// Get the value of the first arc
-    fn first_arc(self) -> u32 {
-        self.0 as u32 / (SECOND_ARC_MAX + 1)
+    fn first_arc(self) -> Arc {
+        self.0 as Arc / (SECOND_ARC_MAX + 1)
     }
 
     /// Get the value of the second arc
-    fn second_arc(self) -> u32 {
-        self.0 as u32 % (SECOND_ARC_MAX + 1)
+    fn second_arc(self) -> Arc {
+        self.0 as Arc % (SECOND_ARC_MAX + 1)
     }
 }
 
@@ -410,8 +461,8 @@ impl TryFrom<u8> for RootArcs {
     type Error = Error;
 
     fn try_from(octet: u8) -> Result<Self> {
-        let first = octet as u32 / (SECOND_ARC_MAX + 1);
-        let second = octet as u32 % (SECOND_ARC_MAX + 1);
+        let first = octet as Arc / (SECOND_ARC_MAX + 1);
+        let second = octet as Arc % (SECOND_ARC_MAX + 1);
         Self::new(first, second)
     }
 }
@@ -429,13 +480,13 @@ struct LowerArcs {
     length: u8,
 
     /// "Lower" arc values.
-    arcs: [u32; MAX_LOWER_ARCS],
+    arcs: [Arc; MAX_LOWER_ARCS],
 }
 
 impl LowerArcs {
     /// Create new [`LowerArcs`] from an array and length, validating length
     /// is in range (1..MAX_LOWER_ARCS)
-    fn new(arcs: [u32; MAX_LOWER_ARCS], length: usize) -> Result<Self> {
+    fn new(arcs: [Arc; MAX_LOWER_ARCS], length: usize) -> Result<Self> {
         if length > 0 && length < MAX_LOWER_ARCS {
             Ok(Self {
                 arcs,
@@ -448,7 +499,7 @@ impl LowerArcs {
 
     /// Parse [`LowerArcs`] from ASN.1 BER.
     fn from_ber(mut bytes: &[u8]) -> Result<Self> {
-        let mut arcs = [0u32; MAX_LOWER_ARCS];
+        let mut arcs = [0; MAX_LOWER_ARCS];
         let mut index = 0;
 
         while !bytes.is_empty() {
@@ -462,7 +513,7 @@ impl LowerArcs {
 
     /// Helper for parsing [`LowerArcs`] from a string
     fn from_split(split: &mut Split<'_, char>) -> Result<Self> {
-        let mut arcs = [0u32; MAX_LOWER_ARCS];
+        let mut arcs = [0; MAX_LOWER_ARCS];
         let mut length = 0;
 
         for (i, n) in split.enumerate() {
@@ -485,21 +536,21 @@ impl LowerArcs {
     }
 }
 
-impl AsRef<[u32]> for LowerArcs {
-    fn as_ref(&self) -> &[u32] {
+impl AsRef<[Arc]> for LowerArcs {
+    fn as_ref(&self) -> &[Arc] {
         &self.arcs[..self.len()]
     }
 }
 
-impl TryFrom<&[u32]> for LowerArcs {
+impl TryFrom<&[Arc]> for LowerArcs {
     type Error = Error;
 
-    fn try_from(arcs: &[u32]) -> Result<Self> {
+    fn try_from(arcs: &[Arc]) -> Result<Self> {
         if arcs.len() > MAX_LOWER_ARCS {
             return Err(Error);
         }
 
-        let mut lower_arcs = [0u32; MAX_LOWER_ARCS];
+        let mut lower_arcs = [0; MAX_LOWER_ARCS];
         lower_arcs[..arcs.len()].copy_from_slice(arcs);
 
         Ok(Self {
@@ -517,30 +568,27 @@ fn parse_byte(bytes: &mut &[u8]) -> Result<u8> {
 }
 
 /// Parse a base 128 (big endian) integer from a bytestring
-fn parse_base128(bytes: &mut &[u8]) -> Result<u32> {
-    let mut result = 0;
-    let mut shift = 0;
+fn parse_base128(bytes: &mut &[u8]) -> Result<Arc> {
+    let mut result: Arc = 0;
 
     loop {
         let byte = parse_byte(bytes)?;
 
-        if shift == 28 && byte & 0b11110000 != 0 {
-            // Overflow
+        // Overflow: accumulated value must leave room for 7 more bits
+        if result >> (Arc::BITS - 7) != 0 {
             return Err(Error);
         }
 
-        result = result << 7 | (byte & 0b1111111) as u32;
+        result = result << 7 | (byte & 0b1111111) as Arc;
 
         if byte & 0b10000000 == 0 {
             return Ok(result);
         }
-
-        shift += 7;
     }
 }
 
 /// Write the given unsigned integer in base 128
-fn write_base128(bytes: &mut [u8], mut n: u32) -> Result<usize> {
+fn write_base128(bytes: &mut [u8], mut n: Arc) -> Result<usize> {
     let nbytes = base128_len(n);
     let mut i = nbytes.checked_sub(1).expect("length underflow");
     let mut mask = 0;
@@ -558,14 +606,16 @@ fn write_base128(bytes: &mut [u8], mut n: u32) -> Result<usize> {
 }
 
 /// Compute the length of a value when encoded in base 128
-fn base128_len(n: u32) -> usize {
-    match n {
-        0..=0x7f => 1,
-        0x80..=0x3fff => 2,
-        0x4000..=0x1fffff => 3,
-        0x200000..=0x1fffffff => 4,
-        _ => 5,
+fn base128_len(n: Arc) -> usize {
+    let mut len = 1;
+    let mut n = n >> 7;
+
+    while n > 0 {
+        len += 1;
+        n >>= 7;
     }
+
+    len
 }
 
 #[cfg(test)]
@@ -646,6 +696,41 @@ mod tests {
         assert_eq!(EXAMPLE_OID.to_ber(), EXAMPLE_OID_BER);
     }
 
+    #[test]
+    fn large_arcs() {
+        // UUID-derived OID under `2.25` with a 128-bit arc
+        let oid = ObjectIdentifier::new(&[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6]);
+        let string = oid.to_string();
+        assert_eq!(string, "2.25.329800735698586629295641978511506172918");
+        assert_eq!(oid, string.parse::<ObjectIdentifier>().unwrap());
+
+        let mut buffer = [0u8; 32];
+        let ber = oid.write_ber(&mut buffer).unwrap();
+        assert_eq!(oid, ObjectIdentifier::from_ber(ber).unwrap());
+
+        // Arc exceeding 128 bits is rejected when decoding
+        let overlong = [
+            0x69, 0x8f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+        ];
+        assert!(ObjectIdentifier::from_ber(&overlong).is_err());
+    }
+
+    #[test]
+    fn root_arc_packing() {
+        // the root arcs pack additively (1*40 + 39 = 79); OR-packing would
+        // yield 40|39 = 47, i.e. misattribute the OID to `1.7.1`
+        let oid = ObjectIdentifier::new(&[1, 39, 1]);
+
+        let mut buffer = [0u8; 4];
+        let ber = oid.write_ber(&mut buffer).unwrap();
+        assert_eq!(ber, &[79, 1]);
+
+        let decoded = ObjectIdentifier::from_ber(ber).unwrap();
+        assert_eq!(decoded, oid);
+        assert_eq!(decoded.to_string(), "1.39.1");
+    }
+
     #[test]
     #[should_panic]
     fn new_too_short() {
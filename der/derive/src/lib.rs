@@ -6,7 +6,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
-    DataStruct, Field, Generics, Ident, Lifetime, Lit, Meta, MetaList, MetaNameValue, NestedMeta,
+    Attribute, DataEnum, DataStruct, Field, Fields, Generics, Ident, Lifetime, Lit, Meta, MetaList,
+    MetaNameValue, NestedMeta, Path, Type, Variant,
 };
 use synstructure::{decl_derive, Structure};
 
@@ -29,6 +30,25 @@ decl_derive!(
     /// - `bit-string`: performs an intermediate conversion to `der::BitString`
     /// - `octet-string`: performs an intermediate conversion to `der::OctetString`
     ///
+    /// # `#[asn1(context_specific = "...")]` attribute
+    ///
+    /// Marks a field as an `EXPLICIT` context-specific field with the given
+    /// tag number, e.g. `[0]`. The field must be an `Option<T>` where `T`
+    /// converts to/from `der::Any`; absent fields decode as `None` and
+    /// `None` fields are omitted when encoding (i.e. the field is
+    /// `OPTIONAL`).
+    ///
+    /// # `#[asn1(default = "...")]` attribute
+    ///
+    /// Marks a field as having a `DEFAULT` value produced by the given
+    /// function path, e.g. `#[asn1(default = "Version::default")]`. Fields
+    /// equal to their default are omitted when encoding, as DER requires.
+    /// The field type must impl `Clone` and `PartialEq`.
+    ///
+    /// Fields of type `Option<T>` are decoded as ASN.1 `OPTIONAL`,
+    /// yielding `None` without consuming input when the next tag in the
+    /// message doesn't match the field.
+    ///
     /// Note: please open a GitHub Issue if you would like to request support
     /// for additional ASN.1 types.
     derive_der_message
@@ -38,13 +58,67 @@ decl_derive!(
 fn derive_der_message(s: Structure<'_>) -> TokenStream {
     let ast = s.ast();
 
-    // TODO(tarcieri): enum support
     match &ast.data {
         syn::Data::Struct(data) => DeriveStruct::derive(s, data, &ast.generics),
         other => panic!("can't derive `Message` on: {:?}", other),
     }
 }
 
+decl_derive!(
+    [Choice, attributes(asn1)] =>
+
+    /// Derive the `Choice` trait on an enum.
+    ///
+    /// This custom derive macro can be used to automatically impl the
+    /// `Decodable` and `Encodable` traits along with the `Choice` trait
+    /// for any enum representing an ASN.1 `CHOICE`, e.g. X.509's `Time`
+    /// or `GeneralName`.
+    ///
+    /// Each variant must have a single unnamed field whose type impls
+    /// `TryFrom<Any>` and `Encodable`. The variants must map to distinct
+    /// tags: by default the tag is taken from the field type's `Tagged`
+    /// impl, while context-specific alternatives can be annotated with
+    /// `#[asn1(context_specific = "...")]` to match the given tag number
+    /// instead.
+    derive_der_choice
+);
+
+/// Custom derive for `der::Choice`
+fn derive_der_choice(s: Structure<'_>) -> TokenStream {
+    let ast = s.ast();
+
+    match &ast.data {
+        syn::Data::Enum(data) => DeriveChoice::derive(s, data, &ast.generics),
+        other => panic!("can't derive `Choice` on: {:?}", other),
+    }
+}
+
+decl_derive!(
+    [Enumerated, attributes(asn1)] =>
+
+    /// Derive ASN.1 `ENUMERATED` support for an enum.
+    ///
+    /// This custom derive macro can be used to automatically impl the
+    /// `Decodable` and `Encodable` traits for any fieldless enum with
+    /// explicit discriminants, mapping each variant to/from the raw
+    /// enumeration value, e.g. X.509's `CRLReason`.
+    ///
+    /// Decoding a value which is not a member of the enumeration returns
+    /// an error, unless a variant is annotated with `#[asn1(catch_all)]`,
+    /// in which case unknown values decode as that variant.
+    derive_der_enumerated
+);
+
+/// Custom derive for ASN.1 `ENUMERATED` enums
+fn derive_der_enumerated(s: Structure<'_>) -> TokenStream {
+    let ast = s.ast();
+
+    match &ast.data {
+        syn::Data::Enum(data) => DeriveEnumerated::derive(s, data),
+        other => panic!("can't derive `Enumerated` on: {:?}", other),
+    }
+}
+
 /// Derive `Message` on a struct
 // TODO(tarcieri): make sure tags are in the right order and digest is the last field
 struct DeriveStruct {
@@ -54,6 +128,9 @@ struct DeriveStruct {
     /// Bound fields of a struct to be returned
     decode_result: TokenStream,
 
+    /// Bindings for temporary values computed before encoding
+    encode_prelude: TokenStream,
+
     /// Fields of a struct to be serialized
     encode_fields: TokenStream,
 }
@@ -63,6 +140,7 @@ impl DeriveStruct {
         let mut state = Self {
             decode_fields: TokenStream::new(),
             decode_result: TokenStream::new(),
+            encode_prelude: TokenStream::new(),
             encode_fields: TokenStream::new(),
         };
 
@@ -83,14 +161,25 @@ impl DeriveStruct {
     /// Derive code for decoding a field of a message
     fn derive_field_decoder(&mut self, field: &FieldAttrs) {
         let field_name = &field.name;
-        let field_decoder = match field.asn1_type {
-            Some(Asn1Type::BitString) => {
-                quote! { let #field_name = decoder.bit_string()?.try_into()?; }
+        let field_decoder = if let Some(tag_number) = field.context_specific {
+            quote! {
+                let #field_name = decoder
+                    .context_specific(#tag_number)?
+                    .map(TryInto::try_into)
+                    .transpose()?;
             }
-            Some(Asn1Type::OctetString) => {
-                quote! { let #field_name = decoder.octet_string()?.try_into()?; }
+        } else if let Some(default) = &field.default {
+            quote! { let #field_name = decoder.decode_with_default(#default())?; }
+        } else {
+            match field.asn1_type {
+                Some(Asn1Type::BitString) => {
+                    quote! { let #field_name = decoder.bit_string()?.try_into()?; }
+                }
+                Some(Asn1Type::OctetString) => {
+                    quote! { let #field_name = decoder.octet_string()?.try_into()?; }
+                }
+                None => quote! { let #field_name = decoder.decode()?; },
             }
-            None => quote! { let #field_name = decoder.decode()?; },
         };
         field_decoder.to_tokens(&mut self.decode_fields);
 
@@ -101,14 +190,32 @@ impl DeriveStruct {
     /// Derive code for encoding a field of a message
     fn derive_field_encoder(&mut self, field: &FieldAttrs) {
         let field_name = &field.name;
-        let field_encoder = match field.asn1_type {
-            Some(Asn1Type::BitString) => {
-                quote!(&der::BitString::new(&self.#field_name)?,)
-            }
-            Some(Asn1Type::OctetString) => {
-                quote!(&der::OctetString::new(&self.#field_name)?,)
+        let field_encoder = if let Some(tag_number) = field.context_specific {
+            let prelude = quote! {
+                let #field_name = self.#field_name
+                    .clone()
+                    .map(|field| der::ContextSpecific::new(#tag_number, field.into()))
+                    .transpose()?;
+            };
+            prelude.to_tokens(&mut self.encode_prelude);
+            quote!(&#field_name,)
+        } else if let Some(default) = &field.default {
+            let prelude = quote! {
+                let #field_name = Some(self.#field_name.clone())
+                    .filter(|field| field != &#default());
+            };
+            prelude.to_tokens(&mut self.encode_prelude);
+            quote!(&#field_name,)
+        } else {
+            match field.asn1_type {
+                Some(Asn1Type::BitString) => {
+                    quote!(&der::BitString::new(&self.#field_name)?,)
+                }
+                Some(Asn1Type::OctetString) => {
+                    quote!(&der::OctetString::new(&self.#field_name)?,)
+                }
+                None => quote!(&self.#field_name,),
             }
-            None => quote!(&self.#field_name,),
         };
         field_encoder.to_tokens(&mut self.encode_fields);
     }
@@ -122,6 +229,7 @@ impl DeriveStruct {
 
         let decode_fields = self.decode_fields;
         let decode_result = self.decode_result;
+        let encode_prelude = self.encode_prelude;
         let encode_fields = self.encode_fields;
 
         s.gen_impl(quote! {
@@ -144,6 +252,7 @@ impl DeriveStruct {
                 where
                     F: FnOnce(&[&dyn der::Encodable]) -> der::Result<T>,
                 {
+                    #encode_prelude
                     f(&[#encode_fields])
                 }
             }
@@ -151,6 +260,349 @@ impl DeriveStruct {
     }
 }
 
+/// Derive `Choice` on an enum
+struct DeriveChoice {
+    /// Tag checks, one per variant, combined with `||` in `can_decode`
+    can_decode_body: TokenStream,
+
+    /// Tag-dispatching decode attempts in `TryFrom<Any>`
+    decode_body: TokenStream,
+
+    /// Match arms of `Encodable::encoded_len`
+    encoded_len_body: TokenStream,
+
+    /// Match arms of `Encodable::encode`
+    encode_body: TokenStream,
+}
+
+impl DeriveChoice {
+    pub fn derive(s: Structure<'_>, data: &DataEnum, generics: &Generics) -> TokenStream {
+        assert!(
+            !data.variants.is_empty(),
+            "can't derive `Choice` on an empty enum"
+        );
+
+        let mut state = Self {
+            can_decode_body: TokenStream::new(),
+            decode_body: TokenStream::new(),
+            encoded_len_body: TokenStream::new(),
+            encode_body: TokenStream::new(),
+        };
+
+        for variant in &data.variants {
+            state.derive_variant(variant);
+        }
+
+        state.finish(&s, generics)
+    }
+
+    /// Derive handling for a particular `CHOICE` variant
+    fn derive_variant(&mut self, variant: &Variant) {
+        let variant_name = &variant.ident;
+        let field_type = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            other => panic!(
+                "can't derive `Choice` on variant `{}`: must have a single unnamed field, got {:?}",
+                variant_name, other
+            ),
+        };
+
+        self.derive_variant_decoder(variant_name, field_type, &variant.attrs);
+        self.derive_variant_encoder(variant_name);
+    }
+
+    /// Derive tag matching and decoding for a `CHOICE` variant
+    fn derive_variant_decoder(&mut self, name: &Ident, field_type: &Type, attrs: &[Attribute]) {
+        let tag_check = if let Some(tag_number) = parse_context_specific_attr(attrs, name) {
+            quote! {
+                matches!(
+                    tag,
+                    der::Tag::ContextSpecific { number, .. } if number.value() == #tag_number
+                )
+            }
+        } else {
+            quote!(<#field_type as der::Tagged>::TAG == tag)
+        };
+
+        if !self.can_decode_body.is_empty() {
+            quote!(||).to_tokens(&mut self.can_decode_body);
+        }
+
+        tag_check.to_tokens(&mut self.can_decode_body);
+
+        let variant_decoder = quote! {
+            if { let tag = any.tag(); #tag_check } {
+                return any.try_into().map(Self::#name);
+            }
+        };
+        variant_decoder.to_tokens(&mut self.decode_body);
+    }
+
+    /// Derive encoding for a `CHOICE` variant, delegating to its field
+    fn derive_variant_encoder(&mut self, name: &Ident) {
+        let encoded_len_arm = quote!(Self::#name(value) => value.encoded_len(),);
+        encoded_len_arm.to_tokens(&mut self.encoded_len_body);
+
+        let encode_arm = quote!(Self::#name(value) => value.encode(encoder),);
+        encode_arm.to_tokens(&mut self.encode_body);
+    }
+
+    /// Finish deriving an enum
+    fn finish(self, s: &Structure<'_>, generics: &Generics) -> TokenStream {
+        let lifetime = match parse_lifetime(generics) {
+            Some(lifetime) => quote!(#lifetime),
+            None => quote!('_),
+        };
+
+        let can_decode_body = self.can_decode_body;
+        let decode_body = self.decode_body;
+        let encoded_len_body = self.encoded_len_body;
+        let encode_body = self.encode_body;
+
+        s.gen_impl(quote! {
+            gen impl core::convert::TryFrom<der::Any<#lifetime>> for @Self {
+                type Error = der::Error;
+
+                fn try_from(any: der::Any<#lifetime>) -> der::Result<Self> {
+                    #[allow(unused_imports)]
+                    use core::convert::TryInto;
+
+                    #decode_body
+
+                    Err(der::ErrorKind::UnexpectedTag {
+                        expected: None,
+                        actual: any.tag(),
+                    }
+                    .into())
+                }
+            }
+
+            gen impl der::Choice<#lifetime> for @Self {
+                fn can_decode(tag: der::Tag) -> bool {
+                    #can_decode_body
+                }
+            }
+
+            gen impl der::Encodable for @Self {
+                fn encoded_len(&self) -> der::Result<der::Length> {
+                    match self {
+                        #encoded_len_body
+                    }
+                }
+
+                fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {
+                    match self {
+                        #encode_body
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Derive ASN.1 `ENUMERATED` support on a fieldless enum
+struct DeriveEnumerated {
+    /// Match arms mapping raw enumeration values to variants
+    decode_arms: TokenStream,
+
+    /// Match arms mapping variants to raw enumeration values
+    encode_arms: TokenStream,
+
+    /// Variant annotated with `#[asn1(catch_all)]`, if any
+    catch_all: Option<Ident>,
+}
+
+impl DeriveEnumerated {
+    pub fn derive(s: Structure<'_>, data: &DataEnum) -> TokenStream {
+        assert!(
+            !data.variants.is_empty(),
+            "can't derive `Enumerated` on an empty enum"
+        );
+
+        let mut state = Self {
+            decode_arms: TokenStream::new(),
+            encode_arms: TokenStream::new(),
+            catch_all: None,
+        };
+
+        for variant in &data.variants {
+            state.derive_variant(variant);
+        }
+
+        state.finish(&s)
+    }
+
+    /// Derive handling for a particular `ENUMERATED` variant
+    fn derive_variant(&mut self, variant: &Variant) {
+        let variant_name = &variant.ident;
+
+        if !matches!(variant.fields, Fields::Unit) {
+            panic!(
+                "can't derive `Enumerated` on variant `{}`: only fieldless variants are supported",
+                variant_name
+            );
+        }
+
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => expr,
+            None => panic!(
+                "can't derive `Enumerated` on variant `{}`: missing explicit discriminant",
+                variant_name
+            ),
+        };
+
+        let decode_arm = quote!(#discriminant => Ok(Self::#variant_name),);
+        decode_arm.to_tokens(&mut self.decode_arms);
+
+        let encode_arm = quote!(Self::#variant_name => #discriminant,);
+        encode_arm.to_tokens(&mut self.encode_arms);
+
+        if parse_catch_all_attr(&variant.attrs, variant_name) {
+            if self.catch_all.is_some() {
+                panic!(
+                    "duplicate ASN.1 `catch_all` attribute for variant: {}",
+                    variant_name
+                );
+            }
+
+            self.catch_all = Some(variant_name.clone());
+        }
+    }
+
+    /// Finish deriving an enum
+    fn finish(self, s: &Structure<'_>) -> TokenStream {
+        let decode_arms = self.decode_arms;
+        let encode_arms = self.encode_arms;
+
+        let fallback_arm = match self.catch_all {
+            Some(variant_name) => quote!(_ => Ok(Self::#variant_name),),
+            None => quote! {
+                _ => Err(der::ErrorKind::Value {
+                    tag: der::Tag::Enumerated,
+                }
+                .into()),
+            },
+        };
+
+        s.gen_impl(quote! {
+            gen impl core::convert::TryFrom<der::Any<'_>> for @Self {
+                type Error = der::Error;
+
+                fn try_from(any: der::Any<'_>) -> der::Result<Self> {
+                    match der::Enumerated::try_from(any)?.value() {
+                        #decode_arms
+                        #fallback_arm
+                    }
+                }
+            }
+
+            gen impl der::Encodable for @Self {
+                fn encoded_len(&self) -> der::Result<der::Length> {
+                    der::Enumerated::new(match self {
+                        #encode_arms
+                    })
+                    .encoded_len()
+                }
+
+                fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {
+                    der::Enumerated::new(match self {
+                        #encode_arms
+                    })
+                    .encode(encoder)
+                }
+            }
+
+            gen impl der::Tagged for @Self {
+                const TAG: der::Tag = der::Tag::Enumerated;
+            }
+        })
+    }
+}
+
+/// Parse a `#[asn1(catch_all)]` attribute on an `ENUMERATED` variant.
+fn parse_catch_all_attr(attrs: &[Attribute], name: &Ident) -> bool {
+    let mut catch_all = false;
+
+    for attr in attrs {
+        if !attr.path.is_ident("asn1") {
+            continue;
+        }
+
+        let nested = match attr.parse_meta().expect("error parsing `asn1` attribute") {
+            Meta::List(MetaList { nested, .. }) => nested,
+            other => panic!(
+                "malformed `asn1` attribute for variant `{}`: {:?}",
+                name, other
+            ),
+        };
+
+        for meta in &nested {
+            match meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("catch_all") => {
+                    catch_all = true;
+                }
+                other => panic!(
+                    "malformed `asn1` attribute for variant `{}`: {:?}",
+                    name, other
+                ),
+            }
+        }
+    }
+
+    catch_all
+}
+
+/// Parse a `#[asn1(context_specific = "...")]` attribute on a `CHOICE`
+/// variant, if present.
+fn parse_context_specific_attr(attrs: &[Attribute], name: &Ident) -> Option<u16> {
+    let mut context_specific = None;
+
+    for attr in attrs {
+        if !attr.path.is_ident("asn1") {
+            continue;
+        }
+
+        let nested = match attr.parse_meta().expect("error parsing `asn1` attribute") {
+            Meta::List(MetaList { nested, .. }) => nested,
+            other => panic!(
+                "malformed `asn1` attribute for variant `{}`: {:?}",
+                name, other
+            ),
+        };
+
+        for meta in &nested {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit_str),
+                    ..
+                })) if path.is_ident("context_specific") => {
+                    if context_specific.is_some() {
+                        panic!(
+                            "duplicate ASN.1 `context_specific` attribute for variant: {}",
+                            name
+                        );
+                    }
+
+                    context_specific = Some(lit_str.value().parse::<u16>().unwrap_or_else(|_| {
+                        panic!(
+                            "malformed `context_specific` tag number for variant `{}`: {}",
+                            name,
+                            lit_str.value()
+                        )
+                    }));
+                }
+                other => panic!(
+                    "malformed `asn1` attribute for variant `{}`: {:?}",
+                    name, other
+                ),
+            }
+        }
+    }
+
+    context_specific
+}
+
 /// Parse the first lifetime of the "self" type of the custom derive
 ///
 /// Returns `None` if there is no first lifetime.
@@ -169,6 +621,12 @@ struct FieldAttrs {
 
     /// Value of the `#[asn1(type = "...")]` attribute if provided
     pub asn1_type: Option<Asn1Type>,
+
+    /// Value of the `#[asn1(context_specific = "...")]` attribute if provided
+    pub context_specific: Option<u16>,
+
+    /// Value of the `#[asn1(default = "...")]` attribute if provided
+    pub default: Option<Path>,
 }
 
 impl FieldAttrs {
@@ -181,45 +639,87 @@ impl FieldAttrs {
             .expect("no name on struct field i.e. tuple structs unsupported");
 
         let mut asn1_type = None;
+        let mut context_specific = None;
+        let mut default = None;
 
         for attr in &field.attrs {
             if !attr.path.is_ident("asn1") {
                 continue;
             }
 
-            match attr.parse_meta().expect("error parsing `asn1` attribute") {
-                Meta::List(MetaList { nested, .. }) if nested.len() == 1 => {
-                    match nested.first() {
-                        Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
-                            path,
-                            lit: Lit::Str(lit_str),
-                            ..
-                        }))) => {
-                            // Parse the `type = "..."` attribute
-                            if !path.is_ident("type") {
-                                panic!("unknown `asn1` attribute for field `{}`: {:?}", name, path);
-                            }
-
+            let nested = match attr.parse_meta().expect("error parsing `asn1` attribute") {
+                Meta::List(MetaList { nested, .. }) => nested,
+                other => panic!(
+                    "malformed `asn1` attribute for field `{}`: {:?}",
+                    name, other
+                ),
+            };
+
+            for meta in &nested {
+                match meta {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) => {
+                        if path.is_ident("type") {
                             if asn1_type.is_some() {
                                 panic!("duplicate ASN.1 `type` attribute for field: {}", name);
                             }
 
                             asn1_type = Some(Asn1Type::new(&lit_str.value()));
+                        } else if path.is_ident("context_specific") {
+                            if context_specific.is_some() {
+                                panic!(
+                                    "duplicate ASN.1 `context_specific` attribute for field: {}",
+                                    name
+                                );
+                            }
+
+                            context_specific = Some(lit_str.value().parse().unwrap_or_else(|_| {
+                                panic!(
+                                    "malformed `context_specific` tag number for field `{}`: {}",
+                                    name,
+                                    lit_str.value()
+                                )
+                            }));
+                        } else if path.is_ident("default") {
+                            if default.is_some() {
+                                panic!("duplicate ASN.1 `default` attribute for field: {}", name);
+                            }
+
+                            default = Some(lit_str.parse().unwrap_or_else(|_| {
+                                panic!(
+                                    "malformed `default` function path for field `{}`: {}",
+                                    name,
+                                    lit_str.value()
+                                )
+                            }));
+                        } else {
+                            panic!("unknown `asn1` attribute for field `{}`: {:?}", name, path);
                         }
-                        other => panic!(
-                            "malformed `asn1` attribute for field `{}`: {:?}",
-                            name, other
-                        ),
                     }
+                    other => panic!(
+                        "malformed `asn1` attribute for field `{}`: {:?}",
+                        name, other
+                    ),
                 }
-                other => panic!(
-                    "malformed `asn1` attribute for field `{}`: {:?}",
-                    name, other
-                ),
             }
         }
 
-        Self { name, asn1_type }
+        if context_specific.is_some() && (asn1_type.is_some() || default.is_some()) {
+            panic!(
+                "`context_specific` cannot be combined with other `asn1` attributes for field: {}",
+                name
+            );
+        }
+
+        Self {
+            name,
+            asn1_type,
+            context_specific,
+            default,
+        }
     }
 }
 
@@ -0,0 +1,14 @@
+//! Fuzz target for schema-agnostic TLV decoding via [`der::Any`].
+
+#![no_main]
+
+use der::Decodable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(any) = der::Any::from_bytes(data) {
+        // exercise the accessors as well as the decoder itself
+        let _ = any.header();
+        let _ = any.is_empty();
+    }
+});
@@ -0,0 +1,10 @@
+//! Fuzz target for the debug tree dump, which recurses into constructed
+//! values of unknown documents.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = der::dump(data, &mut String::new());
+});
@@ -0,0 +1,13 @@
+//! Fuzz target for `INTEGER` decoding: machine integers and the
+//! arbitrary-precision [`der::UIntBytes`] type.
+
+#![no_main]
+
+use der::Decodable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = i128::from_bytes(data);
+    let _ = u128::from_bytes(data);
+    let _ = der::UIntBytes::from_bytes(data);
+});
@@ -0,0 +1,10 @@
+//! Fuzz target for `OBJECT IDENTIFIER` decoding.
+
+#![no_main]
+
+use der::Decodable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = der::ObjectIdentifier::from_bytes(data);
+});
@@ -0,0 +1,12 @@
+//! Fuzz target for `REAL` decoding, including its exponent arithmetic.
+
+#![no_main]
+
+use der::Decodable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(real) = der::Real::from_bytes(data) {
+        let _ = real.value();
+    }
+});
@@ -0,0 +1,13 @@
+//! Fuzz target for `SEQUENCE` decoding, walking the nested elements.
+
+#![no_main]
+
+use der::Decodable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(seq) = der::Sequence::from_bytes(data) {
+        // iteration stops after the first malformed element
+        for _ in seq.elements() {}
+    }
+});
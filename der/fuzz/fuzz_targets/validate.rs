@@ -0,0 +1,10 @@
+//! Fuzz target for schema-agnostic canonicality validation, which scans
+//! arbitrary BER/DER including deeply nested structures.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = der::validate(data);
+});
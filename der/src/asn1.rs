@@ -4,11 +4,30 @@
 
 pub(crate) mod any;
 pub(crate) mod bit_string;
+pub(crate) mod bmp_string;
 pub(crate) mod boolean;
+pub(crate) mod context_specific;
+pub(crate) mod enumerated;
+pub(crate) mod general_string;
+pub(crate) mod generalized_time;
+pub(crate) mod ia5_string;
 pub(crate) mod integer;
 pub(crate) mod null;
+pub(crate) mod numeric_string;
 pub(crate) mod octet_string;
 #[cfg(feature = "oid")]
 pub(crate) mod oid;
 pub(crate) mod optional;
+pub(crate) mod printable_string;
+pub(crate) mod real;
+pub(crate) mod relative_oid;
 pub mod sequence;
+pub(crate) mod sequence_of;
+pub(crate) mod set;
+pub(crate) mod set_of;
+pub(crate) mod teletex_string;
+pub(crate) mod universal_string;
+pub(crate) mod utc_time;
+pub(crate) mod utf8_string;
+pub(crate) mod videotex_string;
+pub(crate) mod visible_string;
@@ -1,14 +1,22 @@
 //! ASN.1 `ANY` type.
 
 use crate::{
-    BitString, ByteSlice, Decodable, Decoder, Encodable, Encoder, Error, ErrorKind, Header, Length,
-    Null, OctetString, Result, Sequence, Tag,
+    decoder::{EncodingRules, INDEFINITE_LENGTH_OCTET},
+    BitString, ByteSlice, Choice, Decodable, Decoder, DynTagged, Encodable, Encoder, Error,
+    ErrorKind, GeneralizedTime, Header, Ia5String, Length, Null, OctetString, PrintableString,
+    Result, Sequence, Tag, UtcTime, Utf8String,
 };
 use core::convert::{TryFrom, TryInto};
 
 #[cfg(feature = "oid")]
 use crate::ObjectIdentifier;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
 /// ASN.1 `ANY` type: represents any explicitly tagged ASN.1 value.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Any<'a> {
@@ -24,7 +32,7 @@ impl<'a> Any<'a> {
     pub fn new(tag: Tag, value: &'a [u8]) -> Result<Self> {
         Ok(Self {
             tag,
-            value: ByteSlice::new(value).map_err(|_| ErrorKind::Length { tag })?,
+            value: ByteSlice::new(value)?,
         })
     }
 
@@ -53,6 +61,16 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `GeneralizedTime`
+    pub fn generalized_time(self) -> Result<GeneralizedTime<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `IA5String`
+    pub fn ia5_string(self) -> Result<Ia5String<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `NULL` value
     pub fn null(self) -> Result<Null> {
         self.try_into()
@@ -70,6 +88,21 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `PrintableString`
+    pub fn printable_string(self) -> Result<PrintableString<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `UTCTime`
+    pub fn utc_time(self) -> Result<UtcTime<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `UTF8String`
+    pub fn utf8_string(self) -> Result<Utf8String<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode this value an ASN.1 `SEQUENCE`, creating a new
     /// nested [`Decoder`] and calling the provided argument with it.
     pub fn sequence<F, T>(self, f: F) -> Result<T>
@@ -80,7 +113,7 @@ impl<'a> Any<'a> {
     }
 
     /// Get the ASN.1 DER [`Header`] for this [`Any`] value
-    pub(crate) fn header(self) -> Header {
+    pub fn header(self) -> Header {
         Header {
             tag: self.tag,
             length: self.len(),
@@ -88,12 +121,48 @@ impl<'a> Any<'a> {
     }
 }
 
+impl<'a> Choice<'a> for Any<'a> {
+    fn can_decode(_: Tag) -> bool {
+        true
+    }
+}
+
+impl DynTagged for Any<'_> {
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
 impl<'a> Decodable<'a> for Any<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Any<'a>> {
-        let header = Header::decode(decoder)?;
-        let tag = header.tag;
-        let len = header.length.to_usize();
-        let value = decoder.bytes(len).map_err(|_| ErrorKind::Length { tag })?;
+        let tag = Tag::decode(decoder)?;
+
+        // in BER-lenient mode, accept indefinite-length encodings of
+        // constructed values, whose contents extend to the matching
+        // end-of-contents octets
+        if decoder.encoding_rules() == EncodingRules::Ber
+            && decoder.peek() == Some(INDEFINITE_LENGTH_OCTET)
+        {
+            if !tag.is_constructed() {
+                return decoder.error(ErrorKind::Length { tag });
+            }
+
+            decoder.byte()?;
+            let value = decoder.indefinite_value()?;
+            return Self::new(tag, value);
+        }
+
+        let length = Length::decode(decoder).map_err(|e| {
+            if e.kind() == ErrorKind::Overlength {
+                ErrorKind::Length { tag }.into()
+            } else {
+                e
+            }
+        })?;
+
+        let value = decoder
+            .bytes(length)
+            .map_err(|_| ErrorKind::Length { tag })?;
         Self::new(tag, value)
     }
 }
@@ -116,3 +185,171 @@ impl<'a> TryFrom<&'a [u8]> for Any<'a> {
         Any::from_bytes(bytes)
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Any<'_> {
+    /// Serialized as a `(encoded tag bytes, value bytes)` pair, preserving
+    /// values whose types have no dedicated serde representation.
+    ///
+    /// The full DER encoding of the tag is used (rather than just the
+    /// initial identifier octet) so high tag numbers with continuation
+    /// octets round-trip.
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        // identifier octets are at most 3 bytes long
+        let mut buffer = [0u8; 3];
+        let tag_bytes = self
+            .tag()
+            .encode_to_slice(&mut buffer)
+            .map_err(ser::Error::custom)?;
+        (tag_bytes, self.as_bytes()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for Any<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let (tag_bytes, value) = <(&[u8], &[u8])>::deserialize(deserializer)?;
+        let tag = Tag::from_bytes(tag_bytes).map_err(de::Error::custom)?;
+        Self::new(tag, value).map_err(de::Error::custom)
+    }
+}
+
+/// Heap-backed version of [`Any`] which owns its value, allowing decoded
+/// data to outlive the input buffer.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnyOwned {
+    /// Tag representing the type of the encoded value
+    tag: Tag,
+
+    /// Inner value encoded as bytes
+    value: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl AnyOwned {
+    /// Get the tag for this [`AnyOwned`] type.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Get the raw value for this [`AnyOwned`] type as a byte slice
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Borrow this value as an [`Any`], e.g. in order to decode it as a
+    /// concrete ASN.1 type.
+    pub fn to_any(&self) -> Result<Any<'_>> {
+        Any::new(self.tag, &self.value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<Any<'_>> for AnyOwned {
+    fn from(any: Any<'_>) -> AnyOwned {
+        AnyOwned {
+            tag: any.tag(),
+            value: any.as_bytes().into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> Decodable<'a> for AnyOwned {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<AnyOwned> {
+        Any::decode(decoder).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Encodable for AnyOwned {
+    fn encoded_len(&self) -> Result<Length> {
+        self.to_any()?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.to_any()?.encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl DynTagged for AnyOwned {
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl zeroize::Zeroize for AnyOwned {
+    /// Zeroize the value bytes, e.g. when they hold decoded key material.
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::AnyOwned;
+    use crate::{Decodable, Encodable, Tag};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn owned_value_outlives_input() {
+        let owned = {
+            let bytes = alloc::vec![0x02, 0x01, 0x2A];
+            AnyOwned::from_bytes(&bytes).unwrap()
+        };
+
+        assert_eq!(owned.tag(), Tag::Integer);
+        assert_eq!(i8::try_from(owned.to_any().unwrap()).unwrap(), 42);
+        assert_eq!(owned.to_vec().unwrap(), &[0x02, 0x01, 0x2A][..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_preserves_high_tag_numbers() {
+        use super::Any;
+        use crate::TagNumber;
+        use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
+
+        // `[1000]` (constructed): tag number requires continuation octets
+        let any = Any::new(Tag::context(TagNumber::new(1000), true), &[0x01, 0x02]).unwrap();
+
+        assert_ser_tokens(
+            &any,
+            &[
+                Token::Tuple { len: 2 },
+                Token::Seq { len: Some(3) },
+                Token::U8(0xBF),
+                Token::U8(0x87),
+                Token::U8(0x68),
+                Token::SeqEnd,
+                Token::Seq { len: Some(2) },
+                Token::U8(0x01),
+                Token::U8(0x02),
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+
+        assert_de_tokens(
+            &any,
+            &[
+                Token::Tuple { len: 2 },
+                Token::BorrowedBytes(&[0xBF, 0x87, 0x68]),
+                Token::BorrowedBytes(&[0x01, 0x02]),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
@@ -1,27 +1,343 @@
 //! ASN.1 `BIT STRING` support.
 
-use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use crate::{
+    Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag, Tagged,
+};
 use core::convert::TryFrom;
 
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 /// ASN.1 `BIT STRING` type.
+///
+/// Bit strings are not necessarily octet-aligned: the leading content octet
+/// of the encoding counts how many trailing bits of the final octet are
+/// unused (X.690 §8.6). DER additionally requires those unused bits to be
+/// zero. Named-bit types such as X.509 `KeyUsage` rely on this to encode
+/// only as many bits as are actually set.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct BitString<'a> {
+    /// Number of unused bits in the final octet
+    unused_bits: u8,
+
     /// Inner value
     inner: ByteSlice<'a>,
 }
 
 impl<'a> BitString<'a> {
-    /// Create a new [`BitString`] from a byte slice.
+    /// Maximum number of unused bits in the final octet.
+    const MAX_UNUSED_BITS: u8 = 7;
+
+    /// Create a new octet-aligned [`BitString`] from a byte slice.
     pub fn new(slice: &'a [u8]) -> Result<Self> {
-        ByteSlice::new(slice)
-            .map(|inner| Self { inner })
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
+        Self::with_unused_bits(0, slice)
+    }
+
+    /// Create a new [`BitString`] from an iterator of bits, writing them
+    /// into the provided backing buffer.
+    ///
+    /// The number of bits (and therefore the unused-bits count of the final
+    /// octet) is taken from the iterator's length; trailing `false` bits
+    /// are *not* truncated. Returns [`ErrorKind::Overlength`] if the buffer
+    /// is too small to hold all of the bits.
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>, buf: &'a mut [u8]) -> Result<Self> {
+        let mut bit_len = 0;
+
+        for bit in bits {
+            let byte = buf.get_mut(bit_len / 8).ok_or(ErrorKind::Overlength)?;
+
+            if bit_len % 8 == 0 {
+                *byte = 0;
+            }
+
+            if bit {
+                *byte |= 0x80 >> (bit_len % 8);
+            }
+
+            bit_len += 1;
+        }
+
+        let byte_len = bit_len.div_ceil(8);
+        let unused_bits = (byte_len * 8 - bit_len) as u8;
+        Self::with_unused_bits(unused_bits, &buf[..byte_len])
+    }
+
+    /// Create a new [`BitString`] of exactly `bit_len` bits from a
+    /// big-endian unsigned integer, encoding it into the provided backing
+    /// buffer.
+    ///
+    /// Bit `0` of the result is the most significant of the `bit_len` bits,
+    /// i.e. `BitString::from_u128(value, bit_len, &mut buf)?.to_u128()`
+    /// round-trips. Returns [`ErrorKind::Overlength`] if `bit_len` exceeds
+    /// 128, or [`ErrorKind::Value`] if `value` does not fit in `bit_len`
+    /// bits.
+    pub fn from_u128(value: u128, bit_len: usize, buf: &'a mut [u8; 16]) -> Result<Self> {
+        if bit_len > 128 {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        if bit_len < 128 && (value >> bit_len) != 0 {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        let byte_len = bit_len.div_ceil(8);
+        let unused_bits = (byte_len * 8 - bit_len) as u8;
+
+        // left-align the bits within `byte_len` octets; cannot overflow
+        // since `bit_len + unused_bits == byte_len * 8 <= 128`
+        let aligned = value << unused_bits;
+        buf[..byte_len].copy_from_slice(&aligned.to_be_bytes()[16 - byte_len..]);
+
+        Self::with_unused_bits(unused_bits, &buf[..byte_len])
+    }
+
+    /// Create a new [`BitString`] with the given number of unused bits in
+    /// the final octet of the slice.
+    ///
+    /// Returns an error if `unused_bits` is greater than 7, if a nonzero
+    /// count is given for an empty bit string, or if any of the unused bits
+    /// are set (forbidden in DER).
+    pub fn with_unused_bits(unused_bits: u8, slice: &'a [u8]) -> Result<Self> {
+        if unused_bits > Self::MAX_UNUSED_BITS || (unused_bits != 0 && slice.is_empty()) {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        if let Some(last) = slice.last() {
+            if last & ((1 << unused_bits) - 1) != 0 {
+                return Err(ErrorKind::Noncanonical.into());
+            }
+        }
+
+        Ok(Self {
+            unused_bits,
+            inner: ByteSlice::new(slice)?,
+        })
     }
 
     /// Borrow the inner byte slice.
+    ///
+    /// Any unused bits in the final octet are included (as zeroes).
     pub fn as_bytes(&self) -> &'a [u8] {
         self.inner.as_bytes()
     }
+
+    /// Get the number of unused bits in the final octet.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Get the length of this bit string in bits.
+    pub fn bit_len(&self) -> usize {
+        self.as_bytes().len() * 8 - self.unused_bits as usize
+    }
+
+    /// Get the bit at the given index, where bit `0` is the most
+    /// significant bit of the first octet (the numbering used by ASN.1
+    /// named bits).
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn bit(&self, index: usize) -> Option<bool> {
+        if index >= self.bit_len() {
+            return None;
+        }
+
+        let byte = self.as_bytes()[index / 8];
+        Some(byte & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// Is the bit at the given index set?
+    ///
+    /// Bits beyond the end of the bit string are considered unset, matching
+    /// the named bit list convention (X.680 §22) in which trailing zero
+    /// bits are truncated.
+    pub fn has_bit(&self, index: usize) -> bool {
+        self.bit(index) == Some(true)
+    }
+
+    /// Iterate over the indices of the bits which are set, in ascending
+    /// order.
+    pub fn set_bits(&self) -> SetBits<'a> {
+        SetBits {
+            bit_string: *self,
+            position: 0,
+        }
+    }
+
+    /// Iterate over all of the bits of this bit string in order, starting
+    /// from the most significant bit of the first octet.
+    pub fn bits(&self) -> Bits<'a> {
+        Bits {
+            bit_string: *self,
+            position: 0,
+        }
+    }
+
+    /// Interpret this bit string as a big-endian unsigned integer, with
+    /// bit `0` as the most significant bit, e.g. for ECDSA public keys and
+    /// other bit strings which carry integer values.
+    ///
+    /// Returns [`ErrorKind::Overlength`] for bit strings longer than 128
+    /// bits.
+    pub fn to_u128(&self) -> Result<u128> {
+        if self.bit_len() > 128 {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        let mut value = 0u128;
+
+        for &byte in self.as_bytes() {
+            value = (value << 8) | (byte as u128);
+        }
+
+        Ok(value >> self.unused_bits)
+    }
+
+    /// Get the length of the content octets, including the leading octet
+    /// which counts the unused bits.
+    fn content_len(&self) -> Result<Length> {
+        self.inner.len() + Length::from(1u8)
+    }
+}
+
+/// Iterator over the set bits of a [`BitString`], yielding their indices.
+pub struct SetBits<'a> {
+    /// Bit string being iterated over
+    bit_string: BitString<'a>,
+
+    /// Index of the next bit to examine
+    position: usize,
+}
+
+impl Iterator for SetBits<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(bit) = self.bit_string.bit(self.position) {
+            let index = self.position;
+            self.position += 1;
+
+            if bit {
+                return Some(index);
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator over all of the bits of a [`BitString`] in order, yielding
+/// each as a `bool`.
+pub struct Bits<'a> {
+    /// Bit string being iterated over
+    bit_string: BitString<'a>,
+
+    /// Index of the next bit to yield
+    position: usize,
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        let bit = self.bit_string.bit(self.position)?;
+        self.position += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bit_string.bit_len() - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+/// Named bit list builder backed by an array of `N` octets (i.e. holding
+/// bits numbered `0..N * 8`).
+///
+/// Encoding applies DER's named bit list rule (X.690 §11.2.2): trailing
+/// zero bits are truncated, so the resulting `BIT STRING` ends at the
+/// highest set bit. This is the form X.509 `KeyUsage` and similar flag
+/// extensions require.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NamedBits<const N: usize> {
+    /// Bit storage, most significant bit of the first octet being bit `0`
+    bytes: [u8; N],
+
+    /// Number of bits in the truncated encoding, i.e. one past the highest
+    /// set bit (`0` if no bits are set)
+    bit_len: usize,
+}
+
+impl<const N: usize> NamedBits<N> {
+    /// Create a new [`NamedBits`] with no bits set.
+    pub fn new() -> Self {
+        Self {
+            bytes: [0u8; N],
+            bit_len: 0,
+        }
+    }
+
+    /// Create a new [`NamedBits`] from an iterator of bit indices to set,
+    /// returning [`ErrorKind::Overlength`] if any index is out of range.
+    pub fn from_bits(bits: impl IntoIterator<Item = usize>) -> Result<Self> {
+        let mut result = Self::new();
+
+        for bit in bits {
+            result.set_bit(bit)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Set the bit at the given index, returning
+    /// [`ErrorKind::Overlength`] if it exceeds the capacity `N * 8`.
+    pub fn set_bit(&mut self, index: usize) -> Result<()> {
+        match self.bytes.get_mut(index / 8) {
+            Some(byte) => {
+                *byte |= 0x80 >> (index % 8);
+                self.bit_len = self.bit_len.max(index + 1);
+                Ok(())
+            }
+            None => Err(ErrorKind::Overlength.into()),
+        }
+    }
+
+    /// Is the bit at the given index set?
+    pub fn has_bit(&self, index: usize) -> bool {
+        self.bytes
+            .get(index / 8)
+            .map(|byte| byte & (0x80 >> (index % 8)) != 0)
+            .unwrap_or(false)
+    }
+
+    /// Borrow these bits as a [`BitString`] with trailing zero bits
+    /// truncated.
+    pub fn bit_string(&self) -> Result<BitString<'_>> {
+        let byte_len = self.bit_len.div_ceil(8);
+        let unused_bits = (byte_len * 8 - self.bit_len) as u8;
+        BitString::with_unused_bits(unused_bits, &self.bytes[..byte_len])
+    }
+}
+
+impl<const N: usize> Default for NamedBits<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Encodable for NamedBits<N> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.bit_string()?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.bit_string()?.encode(encoder)
+    }
+}
+
+impl<const N: usize> Tagged for NamedBits<N> {
+    const TAG: Tag = Tag::BitString;
 }
 
 impl AsRef<[u8]> for BitString<'_> {
@@ -41,15 +357,10 @@ impl<'a> TryFrom<Any<'a>> for BitString<'a> {
 
     fn try_from(any: Any<'a>) -> Result<BitString<'a>> {
         any.tag().assert_eq(Tag::BitString)?;
-        Self::new(any.as_bytes())
-    }
-}
 
-impl<'a> From<BitString<'a>> for Any<'a> {
-    fn from(bit_string: BitString<'a>) -> Any<'a> {
-        Any {
-            tag: Tag::BitString,
-            value: bit_string.inner,
+        match any.as_bytes().split_first() {
+            Some((&unused_bits, rest)) => Self::with_unused_bits(unused_bits, rest),
+            None => Err(ErrorKind::Length { tag: Self::TAG }.into()),
         }
     }
 }
@@ -62,14 +373,199 @@ impl<'a> From<BitString<'a>> for &'a [u8] {
 
 impl<'a> Encodable for BitString<'a> {
     fn encoded_len(&self) -> Result<Length> {
-        Any::from(*self).encoded_len()
+        let content_len = self.content_len()?;
+        Header::new(Self::TAG, content_len)?.encoded_len() + content_len
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        Any::from(*self).encode(encoder)
+        Header::new(Self::TAG, self.content_len()?)?.encode(encoder)?;
+        encoder.byte(self.unused_bits)?;
+        encoder.bytes(self.as_bytes())
     }
 }
 
 impl<'a> Tagged for BitString<'a> {
     const TAG: Tag = Tag::BitString;
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for BitString<'_> {
+    /// Serialized as an `(unused_bits, bytes)` pair so that bit strings
+    /// which are not octet-aligned round-trip losslessly.
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        (self.unused_bits(), self.as_bytes()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for BitString<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let (unused_bits, bytes) = <(u8, &[u8])>::deserialize(deserializer)?;
+        Self::with_unused_bits(unused_bits, bytes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitString, NamedBits};
+    use crate::{Decodable, Encodable, ErrorKind};
+
+    /// Octet-aligned `BIT STRING` (zero unused bits)
+    const ALIGNED_EXAMPLE: &[u8] = &[0x03, 0x03, 0x00, 0xAA, 0x55];
+
+    /// X.509 `KeyUsage` with `digitalSignature` and `keyEncipherment` set:
+    /// three bits, the final five unused
+    const KEY_USAGE_EXAMPLE: &[u8] = &[0x03, 0x02, 0x05, 0xA0];
+
+    #[test]
+    fn decode_aligned() {
+        let bit_string = BitString::from_bytes(ALIGNED_EXAMPLE).unwrap();
+        assert_eq!(bit_string.as_bytes(), &[0xAA, 0x55]);
+        assert_eq!(bit_string.unused_bits(), 0);
+        assert_eq!(bit_string.bit_len(), 16);
+    }
+
+    #[test]
+    fn decode_unaligned() {
+        let bit_string = BitString::from_bytes(KEY_USAGE_EXAMPLE).unwrap();
+        assert_eq!(bit_string.unused_bits(), 5);
+        assert_eq!(bit_string.bit_len(), 3);
+
+        assert_eq!(bit_string.bit(0), Some(true));
+        assert_eq!(bit_string.bit(1), Some(false));
+        assert_eq!(bit_string.bit(2), Some(true));
+        assert_eq!(bit_string.bit(3), None);
+    }
+
+    #[test]
+    fn integer_conversions() {
+        // `101` as a 3-bit big-endian integer
+        let bit_string = BitString::with_unused_bits(5, &[0xA0]).unwrap();
+        assert_eq!(bit_string.to_u128().unwrap(), 0b101);
+
+        let mut buffer = [0u8; 16];
+        let from_int = BitString::from_u128(0b101, 3, &mut buffer).unwrap();
+        assert_eq!(from_int, bit_string);
+
+        // leading zero bits are preserved by an explicit `bit_len`
+        let mut buffer = [0u8; 16];
+        let padded = BitString::from_u128(1, 9, &mut buffer).unwrap();
+        assert_eq!(padded.bit_len(), 9);
+        assert_eq!(padded.to_u128().unwrap(), 1);
+
+        // value too large for the requested width
+        let mut buffer = [0u8; 16];
+        assert!(BitString::from_u128(0b1000, 3, &mut buffer).is_err());
+
+        // more than 128 bits
+        let mut buffer = [0u8; 16];
+        assert!(BitString::from_u128(0, 129, &mut buffer).is_err());
+        let long = BitString::new(&[0u8; 17]).unwrap();
+        assert_eq!(long.to_u128().err().unwrap().kind(), ErrorKind::Overlength);
+    }
+
+    #[test]
+    fn bit_iterator() {
+        let bit_string = BitString::with_unused_bits(5, &[0xA0]).unwrap();
+        let bits = bit_string.bits();
+        assert_eq!(bits.len(), 3);
+        assert!(bits.eq([true, false, true]));
+
+        // round trip through an iterator of bools, preserving the length
+        let mut buffer = [0u8; 1];
+        let rebuilt =
+            BitString::from_bits(bit_string.bits(), &mut buffer).unwrap();
+        assert_eq!(rebuilt, bit_string);
+
+        // buffer too small for the supplied bits
+        let mut buffer = [0u8; 1];
+        assert!(BitString::from_bits([false; 9].iter().copied(), &mut buffer).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_preserves_unused_bits() {
+        use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
+
+        let bit_string = BitString::with_unused_bits(5, &[0xA0]).unwrap();
+
+        assert_ser_tokens(
+            &bit_string,
+            &[
+                Token::Tuple { len: 2 },
+                Token::U8(5),
+                Token::Seq { len: Some(1) },
+                Token::U8(0xA0),
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+
+        assert_de_tokens(
+            &bit_string,
+            &[
+                Token::Tuple { len: 2 },
+                Token::U8(5),
+                Token::BorrowedBytes(&[0xA0]),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn encode() {
+        let bit_string = BitString::with_unused_bits(5, &[0xA0]).unwrap();
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            bit_string.encode_to_slice(&mut buffer).unwrap(),
+            KEY_USAGE_EXAMPLE
+        );
+    }
+
+    #[test]
+    fn named_bits() {
+        // `KeyUsage` holds 9 named bits, so 2 octets of storage
+        let mut bits = NamedBits::<2>::new();
+        bits.set_bit(0).unwrap();
+        bits.set_bit(2).unwrap();
+
+        assert!(bits.has_bit(0));
+        assert!(!bits.has_bit(1));
+        assert!(!bits.has_bit(100));
+        assert_eq!(bits.set_bit(16).err().unwrap().kind(), ErrorKind::Overlength);
+
+        // trailing zero bits are truncated when encoding
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            bits.encode_to_slice(&mut buffer).unwrap(),
+            KEY_USAGE_EXAMPLE
+        );
+
+        let decoded = BitString::from_bytes(KEY_USAGE_EXAMPLE).unwrap();
+        let mut set_bits = decoded.set_bits();
+        assert_eq!(set_bits.next(), Some(0));
+        assert_eq!(set_bits.next(), Some(2));
+        assert_eq!(set_bits.next(), None);
+
+        assert_eq!(NamedBits::from_bits(decoded.set_bits()), Ok(bits));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // missing the unused bits octet
+        let err = BitString::from_bytes(&[0x03, 0x00]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: super::Tag::BitString });
+
+        // more than 7 unused bits
+        assert!(BitString::from_bytes(&[0x03, 0x02, 0x08, 0xFF]).is_err());
+
+        // nonzero unused bits in an empty bit string
+        assert!(BitString::from_bytes(&[0x03, 0x01, 0x05]).is_err());
+
+        // unused bits which are set
+        let err = BitString::from_bytes(&[0x03, 0x02, 0x05, 0xA1]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+    }
+}
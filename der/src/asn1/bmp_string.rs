@@ -0,0 +1,174 @@
+//! ASN.1 `BMPString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// ASN.1 `BMPString` type.
+///
+/// Used by PKCS#12 friendly names and some legacy certificates. Values
+/// are UCS-2: a sequence of 16-bit big-endian code units restricted to
+/// the Basic Multilingual Plane, so the length in bytes is always even
+/// and surrogate code points are rejected with [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BmpString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> BmpString<'a> {
+    /// Create a new [`BmpString`] from its big-endian UCS-2 bytes,
+    /// validating the length and code points.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        let mut units = slice.chunks_exact(2);
+
+        for unit in &mut units {
+            let unit = u16::from_be_bytes([unit[0], unit[1]]);
+            if (0xD800..=0xDFFF).contains(&unit) {
+                return Err(ErrorKind::CharSet { tag: Self::TAG }.into());
+            }
+        }
+
+        if !units.remainder().is_empty() {
+            return Err(ErrorKind::Length { tag: Self::TAG }.into());
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw UCS-2 bytes of this [`BmpString`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Iterate over the characters of this [`BmpString`].
+    pub fn chars(&self) -> BmpChars<'a> {
+        BmpChars {
+            bytes: self.as_bytes(),
+        }
+    }
+}
+
+/// Iterator over the characters of a [`BmpString`].
+pub struct BmpChars<'a> {
+    /// Remaining UCS-2 bytes
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for BmpChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self.bytes {
+            [hi, lo, rest @ ..] => {
+                self.bytes = rest;
+                let unit = u16::from_be_bytes([*hi, *lo]);
+
+                // Surrogates were rejected by `BmpString::new`, so every
+                // remaining code unit is a valid `char`
+                core::char::from_u32(unit as u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl AsRef<[u8]> for BmpString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&BmpString<'a>> for BmpString<'a> {
+    fn from(value: &BmpString<'a>) -> BmpString<'a> {
+        *value
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<BmpString<'_>> for String {
+    fn from(bmp_string: BmpString<'_>) -> String {
+        bmp_string.chars().collect()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for BmpString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<BmpString<'a>> {
+        any.tag().assert_eq(Tag::BmpString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<BmpString<'a>> for Any<'a> {
+    fn from(bmp_string: BmpString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::BmpString,
+            value: bmp_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for BmpString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for BmpString<'a> {
+    const TAG: Tag = Tag::BmpString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BmpString;
+    use crate::{Decodable, Encodable};
+
+    /// `test` encoded as a `BMPString`, e.g. a PKCS#12 friendly name
+    const EXAMPLE: &[u8] = &[0x1e, 0x08, 0x00, 0x74, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74];
+
+    #[test]
+    fn decode() {
+        let string = BmpString::from_bytes(EXAMPLE).unwrap();
+        let mut chars = string.chars();
+        assert_eq!(chars.next(), Some('t'));
+        assert_eq!(chars.next(), Some('e'));
+        assert_eq!(chars.next(), Some('s'));
+        assert_eq!(chars.next(), Some('t'));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn encode() {
+        let string = BmpString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 10];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // odd number of bytes
+        assert!(BmpString::new(&[0x00]).is_err());
+        // surrogate code point
+        assert!(BmpString::new(&[0xD8, 0x00]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string() {
+        use alloc::string::String;
+        let string = BmpString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(String::from(string), "test");
+    }
+}
@@ -0,0 +1,220 @@
+//! Context-specific (`[N]` tagged) values.
+
+use crate::{
+    Any, Choice, Decodable, DynTagged, Encodable, Encoder, Error, ErrorKind, Header, Length,
+    Result, Tag, TagMode, TagNumber,
+};
+use core::convert::TryFrom;
+
+/// Context-specific tagged ASN.1 value.
+///
+/// The tag number is unique to the enclosing structure, e.g. X.509
+/// TBSCertificate's `[0] version` and `[3] extensions` fields.
+///
+/// In the default `EXPLICIT` tagging environment the field's contents are
+/// the complete encoding of the inner value, carried here as an [`Any`]
+/// so callers can convert it to the schema-defined type. `IMPLICIT`
+/// tagging (see [`ContextSpecific::new_implicit`]) instead replaces the
+/// inner value's tag, so only its content octets are written.
+///
+/// See also [`Decoder::context_specific`] and
+/// [`Decoder::context_specific_implicit`] for decoding `OPTIONAL`
+/// context-specific fields in one step.
+///
+/// [`Decoder::context_specific`]: crate::Decoder::context_specific
+/// [`Decoder::context_specific_implicit`]: crate::Decoder::context_specific_implicit
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ContextSpecific<'a> {
+    /// Context-specific tag number
+    tag_number: u16,
+
+    /// Tagging mode of the field
+    tag_mode: TagMode,
+
+    /// Value of the field.
+    ///
+    /// In `IMPLICIT` mode this carries the inner value's own tag, which is
+    /// replaced by the context-specific tag when encoding.
+    value: Any<'a>,
+}
+
+impl<'a> ContextSpecific<'a> {
+    /// Create a new `EXPLICIT` [`ContextSpecific`] from a tag number and
+    /// inner value.
+    pub fn new(tag_number: u16, value: Any<'a>) -> Result<Self> {
+        Self::with_mode(tag_number, TagMode::Explicit, value)
+    }
+
+    /// Create a new `IMPLICIT` [`ContextSpecific`] from a tag number and
+    /// inner value, whose tag will be replaced when encoding.
+    pub fn new_implicit(tag_number: u16, value: Any<'a>) -> Result<Self> {
+        Self::with_mode(tag_number, TagMode::Implicit, value)
+    }
+
+    /// Create a new [`ContextSpecific`] with the given tagging mode.
+    fn with_mode(tag_number: u16, tag_mode: TagMode, value: Any<'a>) -> Result<Self> {
+        if Tag::context_specific(tag_number).is_none() {
+            return Err(ErrorKind::Overflow.into());
+        }
+
+        Ok(Self {
+            tag_number,
+            tag_mode,
+            value,
+        })
+    }
+
+    /// Get the context-specific tag number of this field.
+    pub fn tag_number(self) -> u16 {
+        self.tag_number
+    }
+
+    /// Get the tagging mode of this field.
+    pub fn tag_mode(self) -> TagMode {
+        self.tag_mode
+    }
+
+    /// Get the inner value of this field.
+    pub fn value(self) -> Any<'a> {
+        self.value
+    }
+
+}
+
+impl DynTagged for ContextSpecific<'_> {
+    /// Get the outer context-specific [`Tag`].
+    ///
+    /// `EXPLICIT` fields are always constructed; `IMPLICIT` fields
+    /// preserve the form of the underlying type.
+    fn tag(&self) -> Tag {
+        let constructed = match self.tag_mode {
+            TagMode::Explicit => true,
+            TagMode::Implicit => self.value.tag().is_constructed(),
+        };
+
+        Tag::ContextSpecific {
+            constructed,
+            number: TagNumber::new(self.tag_number),
+        }
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for ContextSpecific<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<ContextSpecific<'a>> {
+        let tag_number = match any.tag() {
+            Tag::ContextSpecific { number, .. } => number.value(),
+            actual => {
+                return Err(ErrorKind::UnexpectedTag {
+                    expected: None,
+                    actual,
+                }
+                .into())
+            }
+        };
+
+        Ok(Self {
+            tag_number,
+            tag_mode: TagMode::Explicit,
+            value: Any::from_bytes(any.as_bytes())?,
+        })
+    }
+}
+
+impl<'a> Choice<'a> for ContextSpecific<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(tag, Tag::ContextSpecific { .. })
+    }
+}
+
+impl<'a> Encodable for ContextSpecific<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        let inner_len = match self.tag_mode {
+            TagMode::Explicit => self.value.encoded_len()?,
+            TagMode::Implicit => self.value.len(),
+        };
+
+        Header::new(self.tag(), inner_len)?.encoded_len() + inner_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self.tag_mode {
+            TagMode::Explicit => {
+                Header::new(self.tag(), self.value.encoded_len()?)?.encode(encoder)?;
+                self.value.encode(encoder)
+            }
+            TagMode::Implicit => {
+                Header::new(self.tag(), self.value.len())?.encode(encoder)?;
+                encoder.bytes(self.value.as_bytes())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContextSpecific;
+    use crate::{Decodable, Decoder, Encodable, OctetString};
+    use core::convert::{TryFrom, TryInto};
+
+    /// X.509 TBSCertificate `[0] version` field holding `INTEGER 2` (v3)
+    const EXAMPLE: &[u8] = &[0xA0, 0x03, 0x02, 0x01, 0x02];
+
+    #[test]
+    fn decode() {
+        let field = ContextSpecific::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(field.tag_number(), 0);
+
+        let version: i8 = field.value().try_into().unwrap();
+        assert_eq!(version, 2);
+    }
+
+    #[test]
+    fn encode() {
+        let field = ContextSpecific::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 8];
+        assert_eq!(EXAMPLE, field.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn optional_fields() {
+        // `[1] BOOLEAN` followed by a plain INTEGER
+        let mut decoder = Decoder::new(&[0xA1, 0x03, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x2A]);
+
+        // a `[0]` field is absent, so nothing is consumed
+        assert_eq!(decoder.context_specific(0).unwrap(), None);
+
+        let field = decoder.context_specific(1).unwrap().unwrap();
+        assert!(bool::try_from(field).unwrap());
+
+        assert_eq!(decoder.context_specific(2).unwrap(), None);
+        assert_eq!(decoder.decode::<i8>().unwrap(), 42);
+    }
+
+    #[test]
+    fn decode_implicit() {
+        // `[0] IMPLICIT OCTET STRING` whose contents are not a valid TLV
+        let mut decoder = Decoder::new(&[0xA0, 0x04, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let value = decoder
+            .context_specific_implicit::<OctetString<'_>>(0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.as_bytes(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn encode_implicit() {
+        let octet_string = OctetString::new(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+        let field = ContextSpecific::new_implicit(0, octet_string.into()).unwrap();
+
+        // the `OCTET STRING` type is primitive, so the identifier octet
+        // uses the primitive form as well
+        let mut buffer = [0u8; 8];
+        assert_eq!(
+            field.encode_to_slice(&mut buffer).unwrap(),
+            &[0x80, 0x04, 0xDE, 0xAD, 0xBE, 0xEF]
+        );
+    }
+}
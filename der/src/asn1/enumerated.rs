@@ -0,0 +1,200 @@
+//! ASN.1 `ENUMERATED` support.
+
+use crate::{Any, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `ENUMERATED` type.
+///
+/// Wrapper over the raw enumeration value, used by e.g. CRL reason codes
+/// and OCSP certificate statuses. Values are restricted to non-negative
+/// integers which fit in a `u32`, which covers every enumeration in the
+/// PKIX ecosystem.
+///
+/// The [`Enumerated::try_into_enum`] helper converts to user-defined Rust
+/// enums which impl `TryFrom<u32>`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Enumerated {
+    /// Inner value
+    value: u32,
+}
+
+impl Enumerated {
+    /// Create a new [`Enumerated`] with the given value.
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    /// Get the raw enumeration value.
+    pub fn value(self) -> u32 {
+        self.value
+    }
+
+    /// Attempt to convert this value into a user-defined Rust enum which
+    /// impls `TryFrom<u32>`, returning [`ErrorKind::Value`] if the value
+    /// is not a member of the enumeration.
+    pub fn try_into_enum<T: TryFrom<u32>>(self) -> Result<T> {
+        T::try_from(self.value).map_err(|_| ErrorKind::Value { tag: Self::TAG }.into())
+    }
+
+    /// Number of bytes in the encoded value.
+    fn value_len(self) -> u8 {
+        match self.value {
+            0..=0x7F => 1,
+            0x80..=0x7FFF => 2,
+            0x8000..=0x7F_FFFF => 3,
+            0x80_0000..=0x7FFF_FFFF => 4,
+            // a leading zero is needed to keep the sign bit clear
+            _ => 5,
+        }
+    }
+}
+
+impl From<u32> for Enumerated {
+    fn from(value: u32) -> Enumerated {
+        Enumerated::new(value)
+    }
+}
+
+impl From<Enumerated> for u32 {
+    fn from(enumerated: Enumerated) -> u32 {
+        enumerated.value()
+    }
+}
+
+impl TryFrom<Any<'_>> for Enumerated {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Enumerated> {
+        let tag = any.tag().assert_eq(Tag::Enumerated)?;
+        let bytes = any.as_bytes();
+
+        let value = match *bytes {
+            [] => return Err(ErrorKind::Length { tag }.into()),
+            // a leading zero is only allowed when needed for the sign bit
+            [0, byte, ..] if byte < 0x80 => return Err(ErrorKind::Noncanonical.into()),
+            // negative values are not valid enumeration members
+            [byte, ..] if byte >= 0x80 => return Err(ErrorKind::Value { tag }.into()),
+            _ => bytes
+                .iter()
+                .try_fold(0u32, |value, &byte| {
+                    value.checked_mul(256)?.checked_add(byte as u32)
+                })
+                .ok_or(ErrorKind::Length { tag })?,
+        };
+
+        Ok(Enumerated::new(value))
+    }
+}
+
+impl Encodable for Enumerated {
+    fn encoded_len(&self) -> Result<Length> {
+        Header {
+            tag: Tag::Enumerated,
+            length: self.value_len().into(),
+        }
+        .encoded_len()?
+            + self.value_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Header {
+            tag: Tag::Enumerated,
+            length: self.value_len().into(),
+        }
+        .encode(encoder)?;
+
+        let bytes = self.value.to_be_bytes();
+        let offset = 4usize.saturating_sub(self.value_len() as usize);
+
+        if self.value_len() == 5 {
+            encoder.byte(0)?;
+        }
+
+        encoder.bytes(&bytes[offset..])
+    }
+}
+
+impl Tagged for Enumerated {
+    const TAG: Tag = Tag::Enumerated;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Enumerated;
+    use crate::{Decodable, Encodable, ErrorKind};
+    use core::convert::TryFrom;
+
+    /// CRL reason code `keyCompromise`
+    const EXAMPLE: &[u8] = &[0x0a, 0x01, 0x01];
+
+    /// Subset of the CRL reason codes from RFC 5280
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    enum CrlReason {
+        Unspecified = 0,
+        KeyCompromise = 1,
+    }
+
+    impl TryFrom<u32> for CrlReason {
+        type Error = ();
+
+        fn try_from(value: u32) -> Result<CrlReason, ()> {
+            match value {
+                0 => Ok(CrlReason::Unspecified),
+                1 => Ok(CrlReason::KeyCompromise),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let enumerated = Enumerated::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(enumerated.value(), 1);
+        assert_eq!(
+            enumerated.try_into_enum::<CrlReason>().unwrap(),
+            CrlReason::KeyCompromise
+        );
+
+        // values outside of the enumeration are rejected by the helper
+        let enumerated = Enumerated::from_bytes(&[0x0a, 0x01, 0x2a]).unwrap();
+        assert!(enumerated.try_into_enum::<CrlReason>().is_err());
+    }
+
+    #[test]
+    fn encode() {
+        let mut buffer = [0u8; 8];
+
+        let encoded = Enumerated::new(1).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+
+        // 128 requires a leading zero for the sign bit
+        let encoded = Enumerated::new(128).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(&[0x0a, 0x02, 0x00, 0x80], encoded);
+
+        let encoded = Enumerated::new(u32::MAX).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(&[0x0a, 0x05, 0x00, 0xff, 0xff, 0xff, 0xff], encoded);
+    }
+
+    #[test]
+    fn roundtrip_extremes() {
+        let mut buffer = [0u8; 8];
+
+        for &value in &[0u32, 0x7F, 0x80, 0x7FFF, 0x8000, u32::MAX] {
+            let encoded = Enumerated::new(value).encode_to_slice(&mut buffer).unwrap();
+            assert_eq!(Enumerated::from_bytes(encoded).unwrap().value(), value);
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // unnecessary leading zero
+        let err = Enumerated::from_bytes(&[0x0a, 0x02, 0x00, 0x01]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+
+        // negative value
+        assert!(Enumerated::from_bytes(&[0x0a, 0x01, 0xff]).is_err());
+
+        // empty value
+        assert!(Enumerated::from_bytes(&[0x0a, 0x00]).is_err());
+    }
+}
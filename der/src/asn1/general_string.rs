@@ -0,0 +1,102 @@
+//! ASN.1 `GeneralString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `GeneralString` type.
+///
+/// `GeneralString` permits arbitrary ISO 2022 escape sequences, which
+/// this crate has no business interpreting, so this type captures the
+/// raw bytes without any charset validation or transcoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GeneralString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> GeneralString<'a> {
+    /// Create a new [`GeneralString`] from a byte slice.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw bytes of this [`GeneralString`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for GeneralString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&GeneralString<'a>> for GeneralString<'a> {
+    fn from(value: &GeneralString<'a>) -> GeneralString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GeneralString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<GeneralString<'a>> {
+        any.tag().assert_eq(Tag::GeneralString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for Any<'a> {
+    fn from(general_string: GeneralString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::GeneralString,
+            value: general_string.inner,
+        }
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for &'a [u8] {
+    fn from(general_string: GeneralString<'a>) -> &'a [u8] {
+        general_string.as_bytes()
+    }
+}
+
+impl<'a> Encodable for GeneralString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for GeneralString<'a> {
+    const TAG: Tag = Tag::GeneralString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralString;
+    use crate::{Decodable, Encodable};
+
+    /// A `GeneralString` containing a non-ASCII byte
+    const EXAMPLE: &[u8] = &[0x1b, 0x03, 0x41, 0xe9, 0x42];
+
+    #[test]
+    fn decode() {
+        let string = GeneralString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_bytes(), &[0x41, 0xe9, 0x42]);
+    }
+
+    #[test]
+    fn encode() {
+        let string = GeneralString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 5];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+}
@@ -0,0 +1,356 @@
+//! ASN.1 `GeneralizedTime` support.
+
+use crate::{
+    datetime::{decode_decimal, encode_decimal},
+    Any, ByteSlice, DateTime, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged,
+    UtcTime,
+};
+use core::{cmp::Ordering, convert::TryFrom};
+
+/// Length of a DER-encoded `GeneralizedTime` value: `YYYYMMDDHHMMSSZ`
+const LENGTH: usize = 15;
+
+/// ASN.1 `GeneralizedTime` type.
+///
+/// This type is used for X.509 certificate validity times in 2050 or
+/// beyond, as well as by CMS and timestamping structures.
+///
+/// DER requires the `YYYYMMDDHHMMSSZ` form: seconds are always present,
+/// fractional seconds are not permitted, and the time is always expressed
+/// in UTC (trailing `Z`). [`GeneralizedTime::new_lenient`] provides an
+/// opt-in mode which additionally accepts (and preserves) fractional
+/// seconds as emitted by some non-conforming producers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GeneralizedTime<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> GeneralizedTime<'a> {
+    /// Create a new [`GeneralizedTime`] from the given `YYYYMMDDHHMMSSZ`
+    /// byte slice, validating the component ranges.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.len() != LENGTH || bytes[LENGTH - 1] != b'Z' {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        Self::validate_components(bytes)?;
+
+        Ok(Self {
+            inner: ByteSlice::new(bytes)?,
+        })
+    }
+
+    /// Create a new [`GeneralizedTime`] accepting the lenient
+    /// `YYYYMMDDHHMMSS[.f...]Z` form with optional fractional seconds.
+    ///
+    /// Strict DER forbids fractional seconds, but some ecosystems (notably
+    /// certain timestamping and EST servers) emit them anyway. The raw
+    /// bytes including the fraction are preserved, so a lenient decode
+    /// followed by an encode round-trips the original message.
+    pub fn new_lenient(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.len() == LENGTH {
+            return Self::new(bytes);
+        }
+
+        // `YYYYMMDDHHMMSS` + `.` + at least one fraction digit + `Z`
+        if bytes.len() < LENGTH + 2
+            || bytes[LENGTH - 1] != b'.'
+            || bytes[bytes.len() - 1] != b'Z'
+        {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        for &byte in &bytes[LENGTH..bytes.len() - 1] {
+            if !byte.is_ascii_digit() {
+                return Err(ErrorKind::Value { tag: Self::TAG }.into());
+            }
+        }
+
+        Self::validate_components(bytes)?;
+
+        Ok(Self {
+            inner: ByteSlice::new(bytes)?,
+        })
+    }
+
+    /// Decode a [`GeneralizedTime`] from the given [`Any`] value, accepting
+    /// the lenient fractional seconds form (see
+    /// [`GeneralizedTime::new_lenient`]).
+    pub fn decode_lenient(any: Any<'a>) -> Result<Self> {
+        any.tag().assert_eq(Tag::GeneralizedTime)?;
+        Self::new_lenient(any.as_bytes())
+    }
+
+    /// Validate the `YYYYMMDDHHMMSS` components at the start of `bytes`.
+    fn validate_components(bytes: &[u8]) -> Result<()> {
+        let century = decode_decimal(bytes[0], bytes[1])?;
+        let year = decode_decimal(bytes[2], bytes[3])?;
+        let month = decode_decimal(bytes[4], bytes[5])?;
+        let day = decode_decimal(bytes[6], bytes[7])?;
+        let hour = decode_decimal(bytes[8], bytes[9])?;
+        let minutes = decode_decimal(bytes[10], bytes[11])?;
+        let seconds = decode_decimal(bytes[12], bytes[13])?;
+
+        let year = (century as u16) * 100 + (year as u16);
+        DateTime::new(year, month, day, hour, minutes, seconds)
+            .map_err(|_| ErrorKind::Value { tag: Self::TAG })?;
+
+        Ok(())
+    }
+
+    /// Create a new [`GeneralizedTime`] from a [`DateTime`], encoding it
+    /// into the provided backing buffer.
+    pub fn from_datetime(datetime: &DateTime, buf: &'a mut [u8; 15]) -> Result<Self> {
+        let year = datetime.year();
+        encode_decimal(&mut buf[0..2], (year / 100) as u8);
+        encode_decimal(&mut buf[2..4], (year % 100) as u8);
+        encode_decimal(&mut buf[4..6], datetime.month());
+        encode_decimal(&mut buf[6..8], datetime.day());
+        encode_decimal(&mut buf[8..10], datetime.hour());
+        encode_decimal(&mut buf[10..12], datetime.minutes());
+        encode_decimal(&mut buf[12..14], datetime.seconds());
+        buf[14] = b'Z';
+        Self::new(buf)
+    }
+
+    /// Borrow the raw `YYYYMMDDHHMMSSZ` bytes of this [`GeneralizedTime`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the date and time this [`GeneralizedTime`] represents as a
+    /// [`DateTime`].
+    pub fn datetime(&self) -> DateTime {
+        // Components were validated by `GeneralizedTime::new`
+        let year = (self.component(0) as u16) * 100 + (self.component(2) as u16);
+        DateTime::new_unchecked(
+            year,
+            self.component(4),
+            self.component(6),
+            self.component(8),
+            self.component(10),
+            self.component(12),
+        )
+    }
+
+    /// Get the fractional seconds digits if this value was decoded
+    /// leniently from the `YYYYMMDDHHMMSS.f...Z` form, or an empty slice
+    /// for strict DER values without a fraction.
+    pub fn fractional_seconds(&self) -> &'a [u8] {
+        let bytes = self.as_bytes();
+        if bytes.len() > LENGTH {
+            &bytes[LENGTH..bytes.len() - 1]
+        } else {
+            &[]
+        }
+    }
+
+    /// Decode the two-digit decimal component at the given offset.
+    ///
+    /// Validity of the digits is checked by [`GeneralizedTime::new`].
+    fn component(&self, offset: usize) -> u8 {
+        let bytes = self.as_bytes();
+        (bytes[offset] - b'0') * 10 + (bytes[offset + 1] - b'0')
+    }
+}
+
+impl Ord for GeneralizedTime<'_> {
+    /// Chronological ordering, so validity-window checks like
+    /// `now <= not_after` can be written directly against decoded values.
+    ///
+    /// Fractional seconds (see [`GeneralizedTime::new_lenient`]) are
+    /// compared numerically, with the shorter digit string ordered first
+    /// between numerically equal fractions (e.g. `.5` vs `.50`) so that the
+    /// ordering remains consistent with [`Eq`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        let fraction = self.fractional_seconds();
+        let other_fraction = other.fractional_seconds();
+
+        self.datetime()
+            .cmp(&other.datetime())
+            .then_with(|| cmp_fractions(fraction, other_fraction))
+            .then_with(|| fraction.len().cmp(&other_fraction.len()))
+    }
+}
+
+impl PartialOrd for GeneralizedTime<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<UtcTime<'_>> for GeneralizedTime<'_> {
+    fn eq(&self, other: &UtcTime<'_>) -> bool {
+        self.datetime() == other.datetime()
+    }
+}
+
+impl PartialOrd<UtcTime<'_>> for GeneralizedTime<'_> {
+    /// Chronological comparison against a [`UtcTime`], e.g. for X.509
+    /// validity windows which mix the two types across the 2050 boundary.
+    /// Fractional seconds on `self` are ignored.
+    fn partial_cmp(&self, other: &UtcTime<'_>) -> Option<Ordering> {
+        Some(self.datetime().cmp(&other.datetime()))
+    }
+}
+
+/// Compare fractional second digit strings numerically, treating missing
+/// trailing digits as zeros (so `.5` and `.50` compare equal).
+fn cmp_fractions(a: &[u8], b: &[u8]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        let digit_a = a.get(i).copied().unwrap_or(b'0');
+        let digit_b = b.get(i).copied().unwrap_or(b'0');
+
+        match digit_a.cmp(&digit_b) {
+            Ordering::Equal => (),
+            other => return other,
+        }
+    }
+
+    Ordering::Equal
+}
+
+impl AsRef<[u8]> for GeneralizedTime<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&GeneralizedTime<'a>> for GeneralizedTime<'a> {
+    fn from(value: &GeneralizedTime<'a>) -> GeneralizedTime<'a> {
+        *value
+    }
+}
+
+impl<'a> From<GeneralizedTime<'a>> for DateTime {
+    fn from(generalized_time: GeneralizedTime<'a>) -> DateTime {
+        generalized_time.datetime()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GeneralizedTime<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<GeneralizedTime<'a>> {
+        any.tag().assert_eq(Tag::GeneralizedTime)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<GeneralizedTime<'a>> for Any<'a> {
+    fn from(generalized_time: GeneralizedTime<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::GeneralizedTime,
+            value: generalized_time.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for GeneralizedTime<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for GeneralizedTime<'a> {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralizedTime;
+    use crate::{DateTime, Decodable, Encodable};
+
+    /// `20501231235959Z` as it would appear in a certificate validity time
+    const EXAMPLE: &[u8] = &[
+        0x18, 0x0f, 0x32, 0x30, 0x35, 0x30, 0x31, 0x32, 0x33, 0x31, 0x32, 0x33, 0x35, 0x39, 0x35,
+        0x39, 0x5a,
+    ];
+
+    #[test]
+    fn decode() {
+        let time = GeneralizedTime::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(time.as_bytes(), b"20501231235959Z");
+
+        let datetime = time.datetime();
+        assert_eq!(datetime.year(), 2050);
+        assert_eq!(datetime.month(), 12);
+        assert_eq!(datetime.day(), 31);
+        assert_eq!(datetime.hour(), 23);
+        assert_eq!(datetime.minutes(), 59);
+        assert_eq!(datetime.seconds(), 59);
+    }
+
+    #[test]
+    fn encode() {
+        let time = GeneralizedTime::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 17];
+        let encoded = time.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn datetime_roundtrip() {
+        let datetime = DateTime::new(2050, 12, 31, 23, 59, 59).unwrap();
+        let mut buffer = [0u8; 15];
+        let time = GeneralizedTime::from_datetime(&datetime, &mut buffer).unwrap();
+        assert_eq!(time.as_bytes(), b"20501231235959Z");
+        assert_eq!(time.datetime(), datetime);
+    }
+
+    #[test]
+    fn ordering() {
+        let not_before = GeneralizedTime::new(b"20501231235959Z").unwrap();
+        let not_after = GeneralizedTime::new(b"20511231235959Z").unwrap();
+        assert!(not_before < not_after);
+
+        // fractional seconds are compared numerically...
+        let earlier = GeneralizedTime::new_lenient(b"20501231235959.4Z").unwrap();
+        let later = GeneralizedTime::new_lenient(b"20501231235959.50Z").unwrap();
+        assert!(not_before < earlier && earlier < later);
+
+        // ...with trailing zeros breaking ties, consistent with `Eq`
+        let short = GeneralizedTime::new_lenient(b"20501231235959.5Z").unwrap();
+        assert!(short < later && short != later);
+
+        // cross-type comparison with `UtcTime` for mixed validity windows
+        let utc = crate::UtcTime::new(b"201221213110Z").unwrap();
+        assert!(utc < not_before);
+        assert!(not_before > utc);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // local time without the trailing `Z`
+        assert!(GeneralizedTime::new(b"205012312359591").is_err());
+        // fractional seconds are not valid DER
+        assert!(GeneralizedTime::new(b"20501231235959.1Z").is_err());
+        // month out of range
+        assert!(GeneralizedTime::new(b"20501331235959Z").is_err());
+    }
+
+    #[test]
+    fn lenient_fractional_seconds() {
+        let time = GeneralizedTime::new_lenient(b"20501231235959.999Z").unwrap();
+        assert_eq!(time.fractional_seconds(), b"999");
+        assert_eq!(time.datetime().seconds(), 59);
+
+        // the fraction is preserved when re-encoding
+        let mut buffer = [0u8; 24];
+        let encoded = time.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(&encoded[2..], b"20501231235959.999Z");
+
+        // strict values decode identically in lenient mode
+        let strict = GeneralizedTime::new_lenient(b"20501231235959Z").unwrap();
+        assert_eq!(strict.fractional_seconds(), b"");
+
+        // fraction must be digits and non-empty
+        assert!(GeneralizedTime::new_lenient(b"20501231235959.Z").is_err());
+        assert!(GeneralizedTime::new_lenient(b"20501231235959.12xZ").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! ASN.1 `IA5String` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::{convert::TryFrom, fmt, str};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// ASN.1 `IA5String` type.
+///
+/// Used for e.g. email addresses and DNS names in `SubjectAltName`
+/// extensions. The character set is the International Alphabet No. 5,
+/// i.e. the lower 128 characters of ASCII. Values containing bytes
+/// outside of that range are rejected with [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ia5String<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> Ia5String<'a> {
+    /// Create a new [`Ia5String`] from a byte slice, validating the
+    /// character set.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        if !slice.is_ascii() {
+            return Err(ErrorKind::CharSet { tag: Self::TAG }.into());
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Borrow the inner value as a `str`.
+    #[allow(clippy::expect_used)]
+    pub fn as_str(&self) -> &'a str {
+        // `Ia5String::new` validated the bytes are ASCII, so they
+        // are always valid UTF-8
+        str::from_utf8(self.as_bytes()).expect("Ia5String charset invariant violated")
+    }
+}
+
+impl AsRef<[u8]> for Ia5String<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for Ia5String<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&Ia5String<'a>> for Ia5String<'a> {
+    fn from(value: &Ia5String<'a>) -> Ia5String<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Ia5String<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Ia5String<'a>> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Ia5String<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Ia5String<'a>> {
+        any.tag().assert_eq(Tag::Ia5String)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<Ia5String<'a>> for Any<'a> {
+    fn from(ia5_string: Ia5String<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::Ia5String,
+            value: ia5_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for Ia5String<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for Ia5String<'a> {
+    const TAG: Tag = Tag::Ia5String;
+}
+
+impl<'a> fmt::Display for Ia5String<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Ia5String<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for Ia5String<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let s = <&str>::deserialize(deserializer)?;
+        Self::new(s.as_bytes()).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ia5String;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+
+    /// `test@example.com` as it would appear in a `SubjectAltName`
+    const EXAMPLE: &[u8] = &[
+        0x16, 0x10, 0x74, 0x65, 0x73, 0x74, 0x40, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2e,
+        0x63, 0x6f, 0x6d,
+    ];
+
+    #[test]
+    fn decode() {
+        let string = Ia5String::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_str(), "test@example.com");
+    }
+
+    #[test]
+    fn encode() {
+        let string = Ia5String::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 18];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn reject_charset_violation() {
+        let err = Ia5String::new(b"not \xc3\xa9 ascii").err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CharSet {
+                tag: Tag::Ia5String
+            }
+        );
+    }
+}
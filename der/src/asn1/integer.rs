@@ -5,41 +5,114 @@ use crate::{
 };
 use core::convert::TryFrom;
 
-impl TryFrom<Any<'_>> for i8 {
-    type Error = Error;
+macro_rules! impl_int_encoding {
+    ($($int:ty => $width:expr),+) => {
+        $(
+            impl TryFrom<Any<'_>> for $int {
+                type Error = Error;
 
-    fn try_from(any: Any<'_>) -> Result<i8> {
-        let tag = any.tag().assert_eq(Tag::Integer)?;
+                fn try_from(any: Any<'_>) -> Result<$int> {
+                    decode_signed_bytes::<$width>(any).map(<$int>::from_be_bytes)
+                }
+            }
 
-        match any.as_bytes() {
-            [x] => Ok(*x as i8),
-            _ => Err(ErrorKind::Length { tag }.into()),
-        }
-    }
+            impl Encodable for $int {
+                fn encoded_len(&self) -> Result<Length> {
+                    let bytes = self.to_be_bytes();
+                    let content_len = Length::try_from(strip_sign_extension(&bytes).len())?;
+                    Header::new(Tag::Integer, content_len)?.encoded_len() + content_len
+                }
+
+                fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+                    let bytes = self.to_be_bytes();
+                    let bytes = strip_sign_extension(&bytes);
+                    Header::new(Tag::Integer, Length::try_from(bytes.len())?)?.encode(encoder)?;
+                    encoder.bytes(bytes)
+                }
+            }
+
+            impl Tagged for $int {
+                const TAG: Tag = Tag::Integer;
+            }
+        )+
+    };
 }
 
-impl Encodable for i8 {
-    fn encoded_len(&self) -> Result<Length> {
-        Header {
-            tag: Tag::Integer,
-            length: 1u8.into(),
-        }
-        .encoded_len()?
-            + 1u8
+impl_int_encoding!(i8 => 1, i16 => 2, i32 => 4, i64 => 8, i128 => 16);
+
+macro_rules! impl_uint_encoding {
+    ($($uint:ty => $width:expr),+) => {
+        $(
+            impl TryFrom<Any<'_>> for $uint {
+                type Error = Error;
+
+                fn try_from(any: Any<'_>) -> Result<$uint> {
+                    let bytes = UIntBytes::try_from(any)?;
+                    let bytes = bytes.as_bytes();
+
+                    if bytes.len() > $width {
+                        return Err(ErrorKind::Length { tag: Tag::Integer }.into());
+                    }
+
+                    let mut buffer = [0u8; $width];
+                    buffer[$width - bytes.len()..].copy_from_slice(bytes);
+                    Ok(<$uint>::from_be_bytes(buffer))
+                }
+            }
+
+            impl Encodable for $uint {
+                fn encoded_len(&self) -> Result<Length> {
+                    let bytes = self.to_be_bytes();
+                    UIntBytes::new(&bytes)?.encoded_len()
+                }
+
+                fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+                    let bytes = self.to_be_bytes();
+                    UIntBytes::new(&bytes)?.encode(encoder)
+                }
+            }
+
+            impl Tagged for $uint {
+                const TAG: Tag = Tag::Integer;
+            }
+        )+
+    };
+}
+
+impl_uint_encoding!(u64 => 8, u128 => 16);
+
+/// Decode the content octets of a two's complement `INTEGER`, sign
+/// extending them to an `N`-byte big endian array.
+fn decode_signed_bytes<const N: usize>(any: Any<'_>) -> Result<[u8; N]> {
+    let tag = any.tag().assert_eq(Tag::Integer)?;
+    let bytes = any.as_bytes();
+
+    match bytes {
+        // missing content octets, or a value too large for the type
+        [] => return Err(ErrorKind::Length { tag }.into()),
+        _ if bytes.len() > N => return Err(ErrorKind::Length { tag }.into()),
+        // leading octets which merely repeat the sign are non-minimal
+        [0x00, byte, ..] if *byte < 0x80 => return Err(ErrorKind::Noncanonical.into()),
+        [0xFF, byte, ..] if *byte >= 0x80 => return Err(ErrorKind::Noncanonical.into()),
+        _ => (),
     }
 
-    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        Header {
-            tag: Tag::Integer,
-            length: 1u8.into(),
+    let mut buffer = [if bytes[0] >= 0x80 { 0xFF } else { 0x00 }; N];
+    buffer[N - bytes.len()..].copy_from_slice(bytes);
+    Ok(buffer)
+}
+
+/// Strip any redundant leading sign bytes from the given big endian
+/// two's complement byte slice, producing its minimal encoding.
+fn strip_sign_extension(mut bytes: &[u8]) -> &[u8] {
+    while let [first, second, ..] = bytes {
+        match (first, second) {
+            (0x00, 0x00..=0x7F) | (0xFF, 0x80..=0xFF) => bytes = &bytes[1..],
+            _ => break,
         }
-        .encode(encoder)?;
-        encoder.byte(*self as u8)
     }
-}
 
-impl Tagged for i8 {
-    const TAG: Tag = Tag::Integer;
+    bytes
 }
 
 /// Raw ASN.1 `INTEGER` type.
@@ -50,8 +123,9 @@ impl Tagged for i8 {
 /// big integer representation of your choice.
 ///
 /// Note that the [`Decodable`][`crate::Decodable`] and [`Encodable`] traits are
-/// impl'd for Rust's integer primitive types ([`i8`] only for now) if you'd like
-/// to work directly with an integer value rather than decoding it yourself.
+/// impl'd for Rust's signed integer primitives ([`i8`] through [`i128`]) if
+/// you'd like to work directly with an integer value rather than decoding it
+/// yourself.
 ///
 /// # ⚠️ Important Usage Notes ⚠️
 ///
@@ -68,9 +142,9 @@ pub struct RawInteger<'a> {
 impl<'a> RawInteger<'a> {
     /// Create a new [`RawInteger`] from a slice.
     pub fn new(slice: &'a [u8]) -> Result<Self> {
-        ByteSlice::new(slice)
-            .map(|inner| Self { inner })
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
     }
 
     /// Borrow the inner byte slice.
@@ -123,11 +197,147 @@ impl<'a> Tagged for RawInteger<'a> {
     const TAG: Tag = Tag::Integer;
 }
 
+/// Unsigned arbitrary-precision ASN.1 `INTEGER` type.
+///
+/// Stores the big endian magnitude of the integer with any leading zeroes
+/// stripped, the form expected by big integer libraries, and intended for
+/// values which exceed machine integers such as RSA moduli and
+/// Diffie-Hellman parameters.
+///
+/// Unlike [`RawInteger`], decoding enforces DER's rules: values must be
+/// encoded in the minimum number of octets, with a single zero byte
+/// prepended when the high bit of the magnitude is set (to keep the
+/// two's complement sign positive). Negative values are rejected.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UIntBytes<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> UIntBytes<'a> {
+    /// Create a new [`UIntBytes`] from a big endian byte slice.
+    ///
+    /// Any leading zeroes are stripped, so zero is represented by an empty
+    /// slice.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(strip_leading_zeroes(bytes))?,
+        })
+    }
+
+    /// Borrow the big endian magnitude of this integer, with leading
+    /// zeroes (including any sign-padding byte) stripped.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the magnitude in bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this integer zero?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Copy the big endian magnitude of this integer into the provided
+    /// fixed-width buffer, left-padding it with zeroes, e.g. to obtain the
+    /// exact field-element width an ECDSA or RSA implementation expects.
+    ///
+    /// Returns [`ErrorKind::Length`] if the magnitude is wider than `buf`.
+    /// The reverse direction is [`UIntBytes::new`], which strips the
+    /// leading zeroes again (and whose [`Encodable`] impl restores the
+    /// sign-padding byte DER requires).
+    pub fn copy_to_slice<'b>(&self, buf: &'b mut [u8]) -> Result<&'b [u8]> {
+        let bytes = self.as_bytes();
+
+        if bytes.len() > buf.len() {
+            return Err(ErrorKind::Length { tag: Self::TAG }.into());
+        }
+
+        let offset = buf.len() - bytes.len();
+        buf[..offset].fill(0);
+        buf[offset..].copy_from_slice(bytes);
+        Ok(buf)
+    }
+
+    /// Convert this integer to a fixed-width big endian byte array,
+    /// left-padded with zeroes; see [`UIntBytes::copy_to_slice`].
+    pub fn to_fixed_bytes<const N: usize>(&self) -> Result<[u8; N]> {
+        let mut bytes = [0u8; N];
+        self.copy_to_slice(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Get the length of the content octets, accounting for the
+    /// sign-padding zero byte when the high bit of the magnitude is set.
+    fn content_len(&self) -> Result<Length> {
+        match self.as_bytes().first() {
+            Some(byte) if *byte < 0x80 => Ok(self.len()),
+            Some(_) => self.len() + Length::from(1u8),
+            // zero is encoded as a single zero octet
+            None => Ok(Length::from(1u8)),
+        }
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for UIntBytes<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<UIntBytes<'a>> {
+        let tag = any.tag().assert_eq(Tag::Integer)?;
+
+        match any.as_bytes() {
+            // missing content octets
+            [] => Err(ErrorKind::Length { tag }.into()),
+            // a leading zero byte is only canonical when needed as
+            // sign padding, i.e. when the next byte has its high bit set
+            [0x00, byte, ..] if *byte < 0x80 => Err(ErrorKind::Noncanonical.into()),
+            // high bit set without sign padding would be negative
+            [byte, ..] if *byte >= 0x80 => Err(ErrorKind::Value { tag }.into()),
+            bytes => Self::new(bytes),
+        }
+    }
+}
+
+impl<'a> Encodable for UIntBytes<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        let content_len = self.content_len()?;
+        Header::new(Self::TAG, content_len)?.encoded_len() + content_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Header::new(Self::TAG, self.content_len()?)?.encode(encoder)?;
+
+        match self.as_bytes().first() {
+            Some(byte) if *byte < 0x80 => (),
+            // insert the sign-padding zero byte (or the zero value itself)
+            _ => encoder.byte(0)?,
+        }
+
+        encoder.bytes(self.as_bytes())
+    }
+}
+
+impl<'a> Tagged for UIntBytes<'a> {
+    const TAG: Tag = Tag::Integer;
+}
+
+/// Strip any leading zeroes from the given big endian byte slice.
+pub(crate) fn strip_leading_zeroes(mut bytes: &[u8]) -> &[u8] {
+    while let Some((0x00, rest)) = bytes.split_first().map(|(byte, rest)| (*byte, rest)) {
+        bytes = rest;
+    }
+
+    bytes
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Decodable, Encodable};
+    use super::UIntBytes;
+    use crate::{Decodable, Encodable, ErrorKind};
 
-    // TODO(tarcieri): larger integer types
     #[test]
     fn decode_i8() {
         // 0
@@ -170,5 +380,173 @@ mod tests {
     #[test]
     fn reject_non_canonical() {
         assert!(i8::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
+
+        // redundant leading 0x00 sign byte
+        let err = i16::from_bytes(&[0x02, 0x02, 0x00, 0x7F]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+
+        // redundant leading 0xFF sign byte
+        let err = i16::from_bytes(&[0x02, 0x02, 0xFF, 0x80]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+
+        // too many content octets for the type
+        assert!(i16::from_bytes(&[0x02, 0x03, 0x01, 0x00, 0x00]).is_err());
+    }
+
+    /// Unsigned values above `i64::MAX`/`i128::MAX` take a sign-padding
+    /// byte, giving 10- and 17-byte worst-case content lengths
+    #[test]
+    fn unsigned_boundaries() {
+        macro_rules! assert_roundtrip {
+            ($value:expr, $encoding:expr) => {
+                let mut buffer = [0u8; 24];
+                assert_eq!(&$encoding[..], $value.encode_to_slice(&mut buffer).unwrap());
+                assert_eq!($value, Decodable::from_bytes(&$encoding[..]).unwrap());
+            };
+        }
+
+        assert_roundtrip!(0u64, [0x02, 0x01, 0x00]);
+        assert_roundtrip!(127u64, [0x02, 0x01, 0x7F]);
+        assert_roundtrip!(128u64, [0x02, 0x02, 0x00, 0x80]);
+        assert_roundtrip!(
+            u64::MAX,
+            [0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert_roundtrip!(
+            u128::MAX,
+            [
+                0x02, 0x11, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF
+            ]
+        );
+
+        // the magnitude of `u64::MAX + 1` needs 9 bytes
+        assert!(u64::from_bytes(&[0x02, 0x09, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).is_err());
+    }
+
+    /// Boundary values for each width round trip through encoding
+    #[test]
+    fn signed_boundaries() {
+        macro_rules! assert_roundtrip {
+            ($value:expr, $encoding:expr) => {
+                let mut buffer = [0u8; 24];
+                assert_eq!(&$encoding[..], $value.encode_to_slice(&mut buffer).unwrap());
+                assert_eq!($value, Decodable::from_bytes(&$encoding[..]).unwrap());
+            };
+        }
+
+        assert_roundtrip!(i8::MIN, [0x02, 0x01, 0x80]);
+        assert_roundtrip!(i8::MAX, [0x02, 0x01, 0x7F]);
+
+        // sign extension is stripped down to a single octet around zero
+        assert_roundtrip!(-1i16, [0x02, 0x01, 0xFF]);
+        assert_roundtrip!(0i16, [0x02, 0x01, 0x00]);
+        assert_roundtrip!(128i16, [0x02, 0x02, 0x00, 0x80]);
+        assert_roundtrip!(-129i16, [0x02, 0x02, 0xFF, 0x7F]);
+
+        assert_roundtrip!(i16::MIN, [0x02, 0x02, 0x80, 0x00]);
+        assert_roundtrip!(i16::MAX, [0x02, 0x02, 0x7F, 0xFF]);
+
+        assert_roundtrip!(i32::MIN, [0x02, 0x04, 0x80, 0x00, 0x00, 0x00]);
+        assert_roundtrip!(i32::MAX, [0x02, 0x04, 0x7F, 0xFF, 0xFF, 0xFF]);
+
+        assert_roundtrip!(
+            i64::MIN,
+            [0x02, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_roundtrip!(
+            i64::MAX,
+            [0x02, 0x08, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+
+        assert_roundtrip!(
+            i128::MIN,
+            [
+                0x02, 0x10, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        );
+        assert_roundtrip!(
+            i128::MAX,
+            [
+                0x02, 0x10, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_uint_bytes() {
+        // zero: single zero octet, empty magnitude
+        let int = UIntBytes::from_bytes(&[0x02, 0x01, 0x00]).unwrap();
+        assert!(int.is_empty());
+
+        // 127: no sign padding needed
+        let int = UIntBytes::from_bytes(&[0x02, 0x01, 0x7F]).unwrap();
+        assert_eq!(int.as_bytes(), &[0x7F]);
+
+        // 128: high bit set, so a sign-padding byte is stripped
+        let int = UIntBytes::from_bytes(&[0x02, 0x02, 0x00, 0x80]).unwrap();
+        assert_eq!(int.as_bytes(), &[0x80]);
+
+        // 65537, the usual RSA public exponent
+        let int = UIntBytes::from_bytes(&[0x02, 0x03, 0x01, 0x00, 0x01]).unwrap();
+        assert_eq!(int.as_bytes(), &[0x01, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn encode_uint_bytes() {
+        let mut buffer = [0u8; 8];
+
+        for example in [
+            &[0x02, 0x01, 0x00][..],
+            &[0x02, 0x01, 0x7F],
+            &[0x02, 0x02, 0x00, 0x80],
+            &[0x02, 0x03, 0x01, 0x00, 0x01],
+        ] {
+            let int = UIntBytes::from_bytes(example).unwrap();
+            assert_eq!(example, int.encode_to_slice(&mut buffer).unwrap());
+        }
+    }
+
+    #[test]
+    fn uint_bytes_to_fixed_width() {
+        // 65537 as a 4-byte big endian field element
+        let int = UIntBytes::from_bytes(&[0x02, 0x03, 0x01, 0x00, 0x01]).unwrap();
+        assert_eq!(int.to_fixed_bytes::<4>().unwrap(), [0x00, 0x01, 0x00, 0x01]);
+
+        // stale buffer contents are overwritten, including the padding
+        let mut buffer = [0xFFu8; 4];
+        assert_eq!(
+            int.copy_to_slice(&mut buffer).unwrap(),
+            &[0x00, 0x01, 0x00, 0x01]
+        );
+
+        // zero fills the entire width
+        let zero = UIntBytes::from_bytes(&[0x02, 0x01, 0x00]).unwrap();
+        assert_eq!(zero.to_fixed_bytes::<4>().unwrap(), [0u8; 4]);
+
+        // magnitude wider than the buffer
+        assert_eq!(
+            int.to_fixed_bytes::<2>().err().unwrap().kind(),
+            ErrorKind::Length { tag: crate::Tag::Integer }
+        );
+
+        // round trip back through `UIntBytes::new`, which strips the padding
+        let padded = int.to_fixed_bytes::<4>().unwrap();
+        assert_eq!(UIntBytes::new(&padded).unwrap(), int);
+    }
+
+    #[test]
+    fn reject_invalid_uint_bytes() {
+        // empty content octets
+        assert!(UIntBytes::from_bytes(&[0x02, 0x00]).is_err());
+
+        // negative
+        assert!(UIntBytes::from_bytes(&[0x02, 0x01, 0x80]).is_err());
+
+        // unnecessary sign padding
+        let err = UIntBytes::from_bytes(&[0x02, 0x02, 0x00, 0x7F]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
     }
 }
@@ -3,6 +3,9 @@
 use crate::{Any, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
 use core::convert::TryFrom;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// ASN.1 `NULL` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Null;
@@ -23,6 +26,8 @@ impl TryFrom<Any<'_>> for Null {
 
 impl<'a> From<Null> for Any<'a> {
     fn from(_: Null) -> Any<'a> {
+        // infallible: an empty value is always in range
+        #[allow(clippy::unwrap_used)]
         Any::new(Tag::Null, &[]).unwrap()
     }
 }
@@ -38,7 +43,24 @@ impl Encodable for Null {
 }
 
 impl Tagged for Null {
-    const TAG: Tag = Tag::Integer;
+    const TAG: Tag = Tag::Null;
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Null {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Null {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        <()>::deserialize(deserializer)?;
+        Ok(Null)
+    }
 }
 
 #[cfg(test)]
@@ -61,4 +83,21 @@ mod tests {
     fn reject_non_canonical() {
         assert!(Null::from_bytes(&[0x05, 0x81, 0x00]).is_err());
     }
+
+    #[test]
+    fn decode_optional() {
+        let mut decoder = crate::Decoder::new(&[0x05, 0x00]);
+        assert_eq!(decoder.decode::<Option<Null>>().unwrap(), Some(Null));
+
+        // a different tag at the cursor means the field is absent
+        let mut decoder = crate::Decoder::new(&[0x02, 0x01, 0x2A]);
+        assert_eq!(decoder.decode::<Option<Null>>().unwrap(), None);
+        assert_eq!(decoder.decode::<i8>().unwrap(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        serde_test::assert_tokens(&Null, &[serde_test::Token::Unit]);
+    }
 }
@@ -0,0 +1,143 @@
+//! ASN.1 `NumericString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::{convert::TryFrom, fmt, str};
+
+/// ASN.1 `NumericString` type.
+///
+/// The character set is restricted to digits and space. Values
+/// containing any other character are rejected with
+/// [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NumericString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> NumericString<'a> {
+    /// Create a new [`NumericString`] from a byte slice, validating the
+    /// character set.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        for &byte in slice {
+            match byte {
+                b'0'..=b'9' | b' ' => (),
+                _ => return Err(ErrorKind::CharSet { tag: Self::TAG }.into()),
+            }
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Borrow the inner value as a `str`.
+    #[allow(clippy::expect_used)]
+    pub fn as_str(&self) -> &'a str {
+        // The character set validated by `NumericString::new` is a
+        // subset of ASCII, so the bytes are always valid UTF-8
+        str::from_utf8(self.as_bytes()).expect("NumericString charset invariant violated")
+    }
+}
+
+impl AsRef<[u8]> for NumericString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for NumericString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&NumericString<'a>> for NumericString<'a> {
+    fn from(value: &NumericString<'a>) -> NumericString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NumericString<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<NumericString<'a>> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for NumericString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<NumericString<'a>> {
+        any.tag().assert_eq(Tag::NumericString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<NumericString<'a>> for Any<'a> {
+    fn from(numeric_string: NumericString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::NumericString,
+            value: numeric_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for NumericString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for NumericString<'a> {
+    const TAG: Tag = Tag::NumericString;
+}
+
+impl<'a> fmt::Display for NumericString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumericString;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+
+    /// `123 456` encoded as a `NumericString`
+    const EXAMPLE: &[u8] = &[0x12, 0x07, 0x31, 0x32, 0x33, 0x20, 0x34, 0x35, 0x36];
+
+    #[test]
+    fn decode() {
+        let string = NumericString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_str(), "123 456");
+    }
+
+    #[test]
+    fn encode() {
+        let string = NumericString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 9];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn reject_charset_violation() {
+        let err = NumericString::new(b"12-34").err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CharSet {
+                tag: Tag::NumericString
+            }
+        );
+    }
+}
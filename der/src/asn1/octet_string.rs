@@ -1,8 +1,14 @@
 //! ASN.1 `OCTET STRING` support.
 
-use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use crate::{Any, ByteSlice, Decodable, Encodable, Encoder, Error, Length, Result, Tag, Tagged};
 use core::convert::TryFrom;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 /// ASN.1 `OCTET STRING` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct OctetString<'a> {
@@ -13,15 +19,34 @@ pub struct OctetString<'a> {
 impl<'a> OctetString<'a> {
     /// Create a new [`OctetString`] from a byte slice.
     pub fn new(slice: &'a [u8]) -> Result<Self> {
-        ByteSlice::new(slice)
-            .map(|inner| Self { inner })
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Create a new [`OctetString`] encapsulating the DER encoding of the
+    /// provided message, using `buf` as backing storage.
+    ///
+    /// This is the encode-side counterpart of
+    /// [`OctetString::decode_nested`] for structures like X.509 extensions
+    /// which nest a complete DER document inside an `OCTET STRING`.
+    pub fn encapsulate(msg: &impl Encodable, buf: &'a mut [u8]) -> Result<Self> {
+        Self::new(msg.encode_to_slice(buf)?)
     }
 
     /// Borrow the inner byte slice.
     pub fn as_bytes(&self) -> &'a [u8] {
         self.inner.as_bytes()
     }
+
+    /// Decode a complete DER message nested inside this `OCTET STRING`,
+    /// e.g. the `extnValue` of an X.509 extension.
+    ///
+    /// As with [`Decodable::from_bytes`], trailing data after the nested
+    /// message is an error.
+    pub fn decode_nested<T: Decodable<'a>>(&self) -> Result<T> {
+        T::from_bytes(self.as_bytes())
+    }
 }
 
 impl AsRef<[u8]> for OctetString<'_> {
@@ -73,3 +98,80 @@ impl<'a> Encodable for OctetString<'a> {
 impl<'a> Tagged for OctetString<'a> {
     const TAG: Tag = Tag::OctetString;
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for OctetString<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for OctetString<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let bytes = <&[u8]>::deserialize(deserializer)?;
+        Self::new(bytes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<Any<'_>> for Vec<u8> {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Vec<u8>> {
+        OctetString::try_from(any).map(|s| s.as_bytes().into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Encodable for Vec<u8> {
+    fn encoded_len(&self) -> Result<Length> {
+        OctetString::new(self)?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetString::new(self)?.encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for Vec<u8> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OctetString;
+    use crate::{Decodable, Encodable};
+
+    /// `OCTET STRING` encapsulating `INTEGER 42`
+    const NESTED_EXAMPLE: &[u8] = &[0x04, 0x03, 0x02, 0x01, 0x2A];
+
+    #[test]
+    fn decode_nested() {
+        let octet_string = OctetString::from_bytes(NESTED_EXAMPLE).unwrap();
+        assert_eq!(octet_string.decode_nested::<i8>().unwrap(), 42);
+
+        // trailing data after the nested message is rejected
+        let tainted = OctetString::new(&[0x02, 0x01, 0x2A, 0x00]).unwrap();
+        assert!(tainted.decode_nested::<i8>().is_err());
+    }
+
+    #[test]
+    fn encapsulate() {
+        let mut inner_buf = [0u8; 3];
+        let octet_string = OctetString::encapsulate(&42i8, &mut inner_buf).unwrap();
+        assert_eq!(octet_string.as_bytes(), &[0x02, 0x01, 0x2A]);
+
+        let mut buf = [0u8; 5];
+        assert_eq!(
+            octet_string.encode_to_slice(&mut buf).unwrap(),
+            NESTED_EXAMPLE
+        );
+    }
+}
@@ -1,17 +1,13 @@
 //! ASN.1 `OPTIONAL` as mapped to Rust's `Option` type
 
-use crate::{Decodable, Decoder, Encodable, Encoder, Length, Result};
+use crate::{Choice, Decodable, Decoder, Encodable, Encoder, Length, Result};
 
 impl<'a, T> Decodable<'a> for Option<T>
 where
-    T: Decodable<'a>,
+    T: Choice<'a>,
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Option<T>> {
-        if decoder.is_finished() {
-            Ok(None)
-        } else {
-            T::decode(decoder).map(Some)
-        }
+        decoder.optional()
     }
 }
 
@@ -35,3 +31,24 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ContextSpecific, Decoder};
+
+    /// Absent `OPTIONAL` fields yield `None` without consuming input,
+    /// regardless of which tag follows.
+    #[test]
+    fn absent_fields_are_skipped() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A]);
+
+        // `[0] ... OPTIONAL` is absent; an `INTEGER` is at the cursor
+        let field: Option<ContextSpecific<'_>> = decoder.decode().unwrap();
+        assert_eq!(field, None);
+
+        assert_eq!(decoder.decode::<Option<i8>>().unwrap(), Some(42));
+
+        // at end of input
+        assert_eq!(decoder.decode::<Option<i8>>().unwrap(), None);
+    }
+}
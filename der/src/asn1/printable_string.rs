@@ -0,0 +1,179 @@
+//! ASN.1 `PrintableString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::{convert::TryFrom, fmt, str};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// ASN.1 `PrintableString` type.
+///
+/// Used for e.g. X.500 distinguished name attributes. The character set
+/// is restricted to uppercase and lowercase letters, digits, space, and
+/// the `'`, `(`, `)`, `+`, `,`, `-`, `.`, `/`, `:`, `=`, `?` symbols.
+/// Values containing any other character are rejected with
+/// [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PrintableString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> PrintableString<'a> {
+    /// Create a new [`PrintableString`] from a byte slice, validating the
+    /// character set.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        for &byte in slice {
+            match byte {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b' '
+                | b'\''
+                | b'('
+                | b')'
+                | b'+'
+                | b','
+                | b'-'
+                | b'.'
+                | b'/'
+                | b':'
+                | b'='
+                | b'?' => (),
+                _ => return Err(ErrorKind::CharSet { tag: Self::TAG }.into()),
+            }
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Borrow the inner value as a `str`.
+    #[allow(clippy::expect_used)]
+    pub fn as_str(&self) -> &'a str {
+        // The character set validated by `PrintableString::new` is a
+        // subset of ASCII, so the bytes are always valid UTF-8
+        str::from_utf8(self.as_bytes()).expect("PrintableString charset invariant violated")
+    }
+}
+
+impl AsRef<[u8]> for PrintableString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for PrintableString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&PrintableString<'a>> for PrintableString<'a> {
+    fn from(value: &PrintableString<'a>) -> PrintableString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PrintableString<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<PrintableString<'a>> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for PrintableString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<PrintableString<'a>> {
+        any.tag().assert_eq(Tag::PrintableString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<PrintableString<'a>> for Any<'a> {
+    fn from(printable_string: PrintableString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::PrintableString,
+            value: printable_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for PrintableString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for PrintableString<'a> {
+    const TAG: Tag = Tag::PrintableString;
+}
+
+impl<'a> fmt::Display for PrintableString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for PrintableString<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for PrintableString<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let s = <&str>::deserialize(deserializer)?;
+        Self::new(s.as_bytes()).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrintableString;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+
+    /// `US` as it would appear in an X.500 country name attribute
+    const EXAMPLE: &[u8] = &[0x13, 0x02, 0x55, 0x53];
+
+    #[test]
+    fn decode() {
+        let string = PrintableString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_str(), "US");
+    }
+
+    #[test]
+    fn encode() {
+        let string = PrintableString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 4];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn reject_charset_violation() {
+        let err = PrintableString::new(b"not@printable").err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CharSet {
+                tag: Tag::PrintableString
+            }
+        );
+    }
+}
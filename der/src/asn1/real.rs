@@ -0,0 +1,307 @@
+//! ASN.1 `REAL` support.
+
+use crate::{Any, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag, Tagged};
+use core::convert::{TryFrom, TryInto};
+
+/// Information octet for the special value `PLUS-INFINITY`
+const PLUS_INFINITY: u8 = 0x40;
+
+/// Information octet for the special value `MINUS-INFINITY`
+const MINUS_INFINITY: u8 = 0x41;
+
+/// Information octet for the special value `NOT-A-NUMBER`
+const NOT_A_NUMBER: u8 = 0x42;
+
+/// Information octet for the special value minus zero
+const MINUS_ZERO: u8 = 0x43;
+
+/// ASN.1 `REAL` type, convertible to/from [`f64`].
+///
+/// Supports the base-2 binary encoding mandated by DER along with the
+/// special values (infinities, `NOT-A-NUMBER`, and minus zero). Decimal
+/// (ISO 6093) encodings and bases other than 2 are rejected with
+/// [`ErrorKind::Value`].
+///
+/// Note that like the underlying [`f64`], this type is not [`Eq`] and
+/// `NOT-A-NUMBER` values do not compare as equal.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Real {
+    /// Inner value
+    value: f64,
+}
+
+impl Real {
+    /// Create a new [`Real`] from an [`f64`].
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Get the value of this [`Real`] as an [`f64`].
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    /// Compute the DER contents octets for this value, returning the
+    /// backing array and the number of bytes used.
+    fn contents(self) -> ([u8; 11], usize) {
+        let mut buf = [0u8; 11];
+
+        if self.value == 0.0 {
+            return if self.value.is_sign_negative() {
+                buf[0] = MINUS_ZERO;
+                (buf, 1)
+            } else {
+                // positive zero is encoded with empty contents
+                (buf, 0)
+            };
+        }
+
+        if self.value.is_nan() {
+            buf[0] = NOT_A_NUMBER;
+            return (buf, 1);
+        }
+
+        if self.value.is_infinite() {
+            buf[0] = if self.value.is_sign_negative() {
+                MINUS_INFINITY
+            } else {
+                PLUS_INFINITY
+            };
+            return (buf, 1);
+        }
+
+        let bits = self.value.to_bits();
+        let sign = (bits >> 63) as u8;
+        let exponent_bits = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = bits & 0xF_FFFF_FFFF_FFFF;
+
+        let (mut mantissa, mut exponent) = if exponent_bits == 0 {
+            // subnormal
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), exponent_bits - 1075)
+        };
+
+        // DER requires the mantissa to be zero or odd
+        while mantissa & 1 == 0 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        // information octet: binary encoding, base 2, scale factor 0
+        let mut pos = 1;
+        buf[0] = 0x80 | (sign << 6);
+
+        if let Ok(exponent) = i8::try_from(exponent) {
+            buf[pos] = exponent as u8;
+            pos += 1;
+        } else {
+            buf[0] |= 0b01;
+            buf[pos..pos + 2].copy_from_slice(&(exponent as i16).to_be_bytes());
+            pos += 2;
+        }
+
+        let mantissa = mantissa.to_be_bytes();
+        let offset = mantissa.iter().take_while(|&&byte| byte == 0).count();
+        buf[pos..pos + 8 - offset].copy_from_slice(&mantissa[offset..]);
+        pos += 8 - offset;
+
+        (buf, pos)
+    }
+}
+
+impl From<f64> for Real {
+    fn from(value: f64) -> Real {
+        Real::new(value)
+    }
+}
+
+impl From<Real> for f64 {
+    fn from(real: Real) -> f64 {
+        real.value()
+    }
+}
+
+impl TryFrom<Any<'_>> for Real {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Real> {
+        let tag = any.tag().assert_eq(Tag::Real)?;
+        let bytes = any.as_bytes();
+
+        let (info, rest) = match bytes {
+            [] => return Ok(Real::new(0.0)),
+            [PLUS_INFINITY] => return Ok(Real::new(f64::INFINITY)),
+            [MINUS_INFINITY] => return Ok(Real::new(f64::NEG_INFINITY)),
+            [NOT_A_NUMBER] => return Ok(Real::new(f64::NAN)),
+            [MINUS_ZERO] => return Ok(Real::new(-0.0)),
+            [info, rest @ ..] => (*info, rest),
+        };
+
+        // only the base-2 binary encoding is supported
+        if info & 0x80 == 0 || info & 0x30 != 0 {
+            return Err(ErrorKind::Value { tag }.into());
+        }
+
+        let scale_factor = ((info >> 2) & 0b11) as i32;
+
+        let (exponent_len, rest) = match info & 0b11 {
+            0b11 => match rest {
+                [len, rest @ ..] => (*len as usize, rest),
+                _ => return Err(ErrorKind::Length { tag }.into()),
+            },
+            code => (code as usize + 1, rest),
+        };
+
+        if exponent_len == 0 || exponent_len > rest.len() || exponent_len > 4 {
+            return Err(ErrorKind::Length { tag }.into());
+        }
+
+        let (exponent_bytes, mantissa_bytes) = rest.split_at(exponent_len);
+        let mut exponent = exponent_bytes[0] as i8 as i32;
+        for &byte in &exponent_bytes[1..] {
+            exponent = exponent
+                .checked_mul(256)
+                .ok_or(ErrorKind::Value { tag })?
+                | byte as i32;
+        }
+
+        if mantissa_bytes.is_empty() || mantissa_bytes.len() > 8 {
+            return Err(ErrorKind::Length { tag }.into());
+        }
+
+        let mut mantissa = 0u64;
+        for &byte in mantissa_bytes {
+            mantissa = (mantissa << 8) | byte as u64;
+        }
+
+        if mantissa == 0 {
+            return Err(ErrorKind::Value { tag }.into());
+        }
+
+        let exponent = exponent
+            .checked_add(scale_factor)
+            .ok_or(ErrorKind::Value { tag })?;
+
+        let mut value = (mantissa as f64) * pow2(exponent);
+        if info & 0x40 != 0 {
+            value = -value;
+        }
+
+        Ok(Real::new(value))
+    }
+}
+
+/// Compute `2^exponent` as an `f64`, saturating to zero/infinity outside
+/// of the representable range.
+fn pow2(exponent: i32) -> f64 {
+    match exponent {
+        i32::MIN..=-1075 => 0.0,
+        // subnormal range
+        -1074..=-1023 => f64::from_bits(1u64 << (exponent + 1074)),
+        -1022..=1023 => f64::from_bits(((exponent + 1023) as u64) << 52),
+        _ => f64::INFINITY,
+    }
+}
+
+impl Encodable for Real {
+    fn encoded_len(&self) -> Result<Length> {
+        let (_, len) = self.contents();
+        let length: Length = len.try_into()?;
+
+        Header {
+            tag: Tag::Real,
+            length,
+        }
+        .encoded_len()?
+            + length
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let (contents, len) = self.contents();
+
+        Header {
+            tag: Tag::Real,
+            length: len.try_into()?,
+        }
+        .encode(encoder)?;
+
+        encoder.bytes(&contents[..len])
+    }
+}
+
+impl Tagged for Real {
+    const TAG: Tag = Tag::Real;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Real;
+    use crate::{Decodable, Encodable};
+
+    #[test]
+    fn roundtrip() {
+        let mut buffer = [0u8; 16];
+
+        for &value in &[
+            0.0,
+            1.0,
+            -1.0,
+            0.5,
+            -0.1,
+            1.234_567_890_123_456_7e42,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            let encoded = Real::new(value).encode_to_slice(&mut buffer).unwrap();
+            let decoded = Real::from_bytes(encoded).unwrap();
+            assert_eq!(decoded.value(), value, "roundtrip failed for {}", value);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        let mut buffer = [0u8; 4];
+
+        let encoded = Real::new(f64::NAN).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, &[0x09, 0x01, 0x42]);
+        assert!(Real::from_bytes(encoded).unwrap().value().is_nan());
+
+        let encoded = Real::new(-0.0).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, &[0x09, 0x01, 0x43]);
+        let decoded = Real::from_bytes(encoded).unwrap();
+        assert_eq!(decoded.value(), 0.0);
+        assert!(decoded.value().is_sign_negative());
+
+        // positive zero has empty contents
+        let encoded = Real::new(0.0).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, &[0x09, 0x00]);
+    }
+
+    #[test]
+    fn encode_one() {
+        // 1.0 = +1 * 2^0 with an odd mantissa
+        let mut buffer = [0u8; 8];
+        let encoded = Real::new(1.0).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, &[0x09, 0x03, 0x80, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // decimal (character) encoding
+        assert!(Real::from_bytes(&[0x09, 0x02, 0x03, 0x31]).is_err());
+        // base 16
+        assert!(Real::from_bytes(&[0x09, 0x03, 0xA0, 0x00, 0x01]).is_err());
+        // missing mantissa
+        assert!(Real::from_bytes(&[0x09, 0x02, 0x80, 0x00]).is_err());
+    }
+
+    #[test]
+    fn reject_exponent_overflow() {
+        // exponent of i32::MAX plus a scale factor of 1 must error rather
+        // than overflow the exponent arithmetic
+        assert!(Real::from_bytes(&[0x09, 0x07, 0x87, 0x04, 0x7F, 0xFF, 0xFF, 0xFF, 0x01]).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+//! ASN.1 `RELATIVE-OID` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `RELATIVE-OID` type: an object identifier relative to some base
+/// OID established by context, used heavily in SNMP-derived structures.
+///
+/// Unlike an absolute `OBJECT IDENTIFIER`, a relative OID has no special
+/// encoding for its first two arcs: every arc is a plain base 128
+/// (big endian, high bit continuation) integer. Arcs can be enumerated
+/// with [`RelativeOid::arcs`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RelativeOid<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> RelativeOid<'a> {
+    /// Create a new [`RelativeOid`] from its BER/DER arc encoding.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        if slice.is_empty() {
+            return Err(ErrorKind::Length { tag: Self::TAG }.into());
+        }
+
+        let mut continuation = false;
+
+        for &byte in slice {
+            // leading 0x80 octets are a non-minimal arc encoding
+            if !continuation && byte == 0x80 {
+                return Err(ErrorKind::Noncanonical.into());
+            }
+
+            continuation = byte & 0x80 != 0;
+        }
+
+        // the final octet of every arc must have its high bit clear
+        if continuation {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw arc encoding of this [`RelativeOid`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Iterate over the arcs of this [`RelativeOid`].
+    ///
+    /// Arcs which do not fit in a `u32` are returned as
+    /// [`ErrorKind::Value`] errors.
+    pub fn arcs(&self) -> Arcs<'a> {
+        Arcs {
+            bytes: self.as_bytes(),
+        }
+    }
+}
+
+/// Iterator over the arcs of a [`RelativeOid`].
+pub struct Arcs<'a> {
+    /// Remaining arc encoding
+    bytes: &'a [u8],
+}
+
+impl Iterator for Arcs<'_> {
+    type Item = Result<u32>;
+
+    fn next(&mut self) -> Option<Result<u32>> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let mut arc = 0u32;
+
+        while let [byte, rest @ ..] = self.bytes {
+            self.bytes = rest;
+
+            arc = match arc.checked_mul(128) {
+                Some(arc) => arc | (byte & 0x7F) as u32,
+                None => {
+                    self.bytes = &[];
+                    return Some(Err(ErrorKind::Value {
+                        tag: Tag::RelativeOid,
+                    }
+                    .into()));
+                }
+            };
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        Some(Ok(arc))
+    }
+}
+
+impl AsRef<[u8]> for RelativeOid<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&RelativeOid<'a>> for RelativeOid<'a> {
+    fn from(value: &RelativeOid<'a>) -> RelativeOid<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for RelativeOid<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<RelativeOid<'a>> {
+        any.tag().assert_eq(Tag::RelativeOid)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<RelativeOid<'a>> for Any<'a> {
+    fn from(oid: RelativeOid<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::RelativeOid,
+            value: oid.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for RelativeOid<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for RelativeOid<'a> {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{Decodable, Encodable};
+
+    /// Relative OID `8571.3.2` from X.690
+    const EXAMPLE: &[u8] = &[0x0d, 0x04, 0xc2, 0x7b, 0x03, 0x02];
+
+    #[test]
+    fn decode() {
+        let oid = RelativeOid::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(oid.as_bytes(), &EXAMPLE[2..]);
+    }
+
+    #[test]
+    fn encode() {
+        let mut buffer = [0u8; 8];
+        let oid = RelativeOid::new(&EXAMPLE[2..]).unwrap();
+        assert_eq!(EXAMPLE, oid.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn arcs() {
+        let oid = RelativeOid::from_bytes(EXAMPLE).unwrap();
+        let mut arcs = oid.arcs();
+        assert_eq!(arcs.next().unwrap().unwrap(), 8571);
+        assert_eq!(arcs.next().unwrap().unwrap(), 3);
+        assert_eq!(arcs.next().unwrap().unwrap(), 2);
+        assert!(arcs.next().is_none());
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // empty value
+        assert!(RelativeOid::from_bytes(&[0x0d, 0x00]).is_err());
+
+        // truncated arc (continuation bit set on the final octet)
+        assert!(RelativeOid::from_bytes(&[0x0d, 0x01, 0x81]).is_err());
+
+        // non-minimal arc encoding
+        assert!(RelativeOid::from_bytes(&[0x0d, 0x02, 0x80, 0x01]).is_err());
+    }
+}
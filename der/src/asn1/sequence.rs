@@ -1,10 +1,10 @@
 //! ASN.1 `SEQUENCE` support.
 
 use crate::{
-    Any, ByteSlice, Decoder, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag,
-    Tagged,
+    Any, ByteSlice, Decodable, Decoder, Encodable, Encoder, Error, Header, Length,
+    Result, Tag, Tagged,
 };
-use core::convert::TryFrom;
+use core::{convert::TryFrom, marker::PhantomData};
 
 /// Obtain the length of an ASN.1 `SEQUENCE` of [`Encodable`] values when
 /// serialized as ASN.1 DER, including the `SEQUENCE` tag and length prefix.
@@ -33,9 +33,9 @@ pub struct Sequence<'a> {
 impl<'a> Sequence<'a> {
     /// Create a new [`Sequence`] from a slice
     pub fn new(slice: &'a [u8]) -> Result<Self> {
-        ByteSlice::new(slice)
-            .map(|inner| Self { inner })
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
     }
 
     /// Borrow the inner byte sequence
@@ -54,6 +54,72 @@ impl<'a> Sequence<'a> {
         let result = f(&mut seq_decoder)?;
         seq_decoder.finish(result)
     }
+
+    /// Decode values nested within a sequence as
+    /// [`Sequence::decode_nested`] does, but skip and ignore any
+    /// well-formed TLVs remaining after the provided [`FnOnce`] returns,
+    /// e.g. fields appended by a newer version of a message's schema.
+    ///
+    /// Malformed trailing data is still an error, and
+    /// [`Sequence::decode_nested`] (which rejects trailing fields) remains
+    /// the default used by the blanket decoding impls.
+    pub fn decode_nested_tolerant<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let mut seq_decoder = Decoder::new(self.as_bytes());
+        let result = f(&mut seq_decoder)?;
+
+        while !seq_decoder.is_finished() {
+            seq_decoder.decode::<Any<'a>>()?;
+        }
+
+        seq_decoder.finish(result)
+    }
+
+    /// Iterate over a homogeneous `SEQUENCE OF` the given [`Decodable`]
+    /// type, lazily decoding one element at a time.
+    pub fn iter<T: Decodable<'a>>(&self) -> SequenceIter<'a, T> {
+        SequenceIter {
+            decoder: Decoder::new(self.as_bytes()),
+            element: PhantomData,
+        }
+    }
+
+    /// Iterate over the TLV elements of this sequence's body as
+    /// schema-agnostic [`Any`] values.
+    ///
+    /// Each element exposes its [`Tag`] and value slice via [`Any::tag`]
+    /// and [`Any::as_bytes`], and its header via [`Any::header`], without
+    /// requiring knowledge of the ASN.1 module the sequence belongs to.
+    pub fn elements(&self) -> SequenceIter<'a, Any<'a>> {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of a homogeneous `SEQUENCE OF`.
+///
+/// Elements are decoded on demand, so malformed input surfaces as an
+/// [`Err`] item at the offending element rather than up front. Iteration
+/// ends after the first error.
+pub struct SequenceIter<'a, T> {
+    /// Decoder for the remaining sequence body
+    decoder: Decoder<'a>,
+
+    /// Element type being decoded
+    element: PhantomData<T>,
+}
+
+impl<'a, T: Decodable<'a>> Iterator for SequenceIter<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.decoder.is_failed() || self.decoder.is_finished() {
+            None
+        } else {
+            Some(self.decoder.decode())
+        }
+    }
 }
 
 impl AsRef<[u8]> for Sequence<'_> {
@@ -93,3 +159,132 @@ impl<'a> Encodable for Sequence<'a> {
 impl<'a> Tagged for Sequence<'a> {
     const TAG: Tag = Tag::Sequence;
 }
+
+/// Obtain the inner length of a `SEQUENCE OF` the given values, excluding
+/// the tag and length.
+fn encoded_len_of<T: Encodable>(values: &[T]) -> Result<Length> {
+    let mut inner_len = Length::zero();
+
+    for value in values {
+        inner_len = (inner_len + value.encoded_len()?)?;
+    }
+
+    Ok(inner_len)
+}
+
+impl<T: Encodable> Encodable for [T] {
+    fn encoded_len(&self) -> Result<Length> {
+        let inner_len = encoded_len_of(self)?;
+        Header::new(Tag::Sequence, inner_len)?.encoded_len() + inner_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let inner_len = encoded_len_of(self)?;
+        Header::new(Tag::Sequence, inner_len)?.encode(encoder)?;
+
+        for value in self {
+            value.encode(encoder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Encodable, const N: usize> Encodable for [T; N] {
+    fn encoded_len(&self) -> Result<Length> {
+        self[..].encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self[..].encode(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequence;
+    use crate::{Decodable, Encodable, Length, Tag};
+
+    /// `SEQUENCE OF` three `INTEGER`s
+    const EXAMPLE: &[u8] = &[0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03];
+
+    #[test]
+    fn iter() {
+        let seq = Sequence::from_bytes(EXAMPLE).unwrap();
+        let mut iter = seq.iter::<i8>();
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert_eq!(iter.next().unwrap().unwrap(), 2);
+        assert_eq!(iter.next().unwrap().unwrap(), 3);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn iter_stops_after_error() {
+        // second element has a truncated length
+        let seq = Sequence::new(&[0x02, 0x01, 0x01, 0x02, 0x05]).unwrap();
+        let mut iter = seq.iter::<i8>();
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn elements() {
+        let seq = Sequence::from_bytes(EXAMPLE).unwrap();
+        let mut elements = seq.elements();
+
+        let first = elements.next().unwrap().unwrap();
+        assert_eq!(first.tag(), Tag::Integer);
+        assert_eq!(first.header().length, Length::from(1u8));
+        assert_eq!(first.as_bytes(), &[0x01]);
+
+        assert_eq!(elements.count(), 2);
+    }
+
+    #[test]
+    fn decode_nested_tolerant() {
+        let seq = Sequence::from_bytes(EXAMPLE).unwrap();
+
+        // decoding only the first two fields is an error in strict mode...
+        let strict = seq.decode_nested(|decoder| {
+            Ok((decoder.decode::<i8>()?, decoder.decode::<i8>()?))
+        });
+        assert!(strict.is_err());
+
+        // ...but tolerant mode skips the trailing field
+        let (first, second) = seq
+            .decode_nested_tolerant(|decoder| {
+                Ok((decoder.decode::<i8>()?, decoder.decode::<i8>()?))
+            })
+            .unwrap();
+        assert_eq!((first, second), (1, 2));
+
+        // malformed trailing data is still rejected
+        let bad = Sequence::new(&[0x02, 0x01, 0x01, 0x02, 0x05]).unwrap();
+        assert!(bad
+            .decode_nested_tolerant(|decoder| decoder.decode::<i8>())
+            .is_err());
+    }
+
+    #[test]
+    fn encode_sequence_of() {
+        let values = [1i8, 2, 3];
+        let mut buffer = [0u8; 16];
+
+        assert_eq!(EXAMPLE, values.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(EXAMPLE, values[..].encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn encode_optional_values() {
+        let mut buffer = [0u8; 4];
+
+        assert_eq!(
+            &[0x02, 0x01, 0x2A],
+            Some(42i8).encode_to_slice(&mut buffer).unwrap()
+        );
+
+        let empty: &[u8] = &[];
+        assert_eq!(empty, None::<i8>.encode_to_slice(&mut buffer).unwrap());
+    }
+}
@@ -0,0 +1,173 @@
+//! ASN.1 `SEQUENCE OF` fixed-capacity container.
+
+use crate::{
+    Any, Decodable, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Sequence, Tag,
+    Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `SEQUENCE OF` backed by an array with a fixed upper bound `N` on
+/// the number of elements.
+///
+/// Unlike [`Sequence::iter`], this container decodes elements eagerly into
+/// owned storage, allowing bounded lists (e.g. algorithm parameter sets)
+/// to be parsed without allocating. Decoding errors with
+/// [`ErrorKind::Overlength`] if the message contains more than `N`
+/// elements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SequenceOf<T, const N: usize> {
+    /// Elements of the sequence
+    elements: [Option<T>; N],
+
+    /// Number of elements currently stored
+    length: usize,
+}
+
+impl<T, const N: usize> SequenceOf<T, N> {
+    /// Create a new [`SequenceOf`] containing no elements.
+    pub fn new() -> Self {
+        Self {
+            elements: [(); N].map(|_| None),
+            length: 0,
+        }
+    }
+
+    /// Add an element to this [`SequenceOf`], returning
+    /// [`ErrorKind::Overlength`] if the capacity `N` has been reached.
+    pub fn add(&mut self, element: T) -> Result<()> {
+        match self.elements.get_mut(self.length) {
+            Some(slot) => {
+                *slot = Some(element);
+                self.length += 1;
+                Ok(())
+            }
+            None => Err(ErrorKind::Overlength.into()),
+        }
+    }
+
+    /// Get an element of this [`SequenceOf`] by its index.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index).and_then(Option::as_ref)
+    }
+
+    /// Iterate over the elements of this [`SequenceOf`].
+    pub fn elements(&self) -> SequenceOfIter<'_, T> {
+        SequenceOfIter {
+            inner: self.elements[..self.length].iter(),
+        }
+    }
+
+    /// Number of elements in this [`SequenceOf`].
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Is this [`SequenceOf`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+impl<T, const N: usize> Default for SequenceOf<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the elements of a [`SequenceOf`].
+pub struct SequenceOfIter<'a, T> {
+    /// Inner iterator over the occupied storage slots
+    inner: core::slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for SequenceOfIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next().and_then(Option::as_ref)
+    }
+}
+
+impl<'a, T: Decodable<'a>, const N: usize> TryFrom<Any<'a>> for SequenceOf<T, N> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<SequenceOf<T, N>> {
+        Sequence::try_from(any)?.decode_nested(|decoder| {
+            let mut sequence_of = Self::new();
+
+            while !decoder.is_finished() {
+                sequence_of.add(decoder.decode()?)?;
+            }
+
+            Ok(sequence_of)
+        })
+    }
+}
+
+impl<T: Encodable, const N: usize> Encodable for SequenceOf<T, N> {
+    fn encoded_len(&self) -> Result<Length> {
+        let inner_len = encoded_len_inner(self.elements())?;
+        Header::new(Tag::Sequence, inner_len)?.encoded_len() + inner_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let inner_len = encoded_len_inner(self.elements())?;
+        Header::new(Tag::Sequence, inner_len)?.encode(encoder)?;
+
+        for element in self.elements() {
+            element.encode(encoder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Tagged for SequenceOf<T, N> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// Obtain the inner length of a sequence of [`Encodable`] values.
+pub(crate) fn encoded_len_inner<'a, T: Encodable + 'a>(
+    mut elements: impl Iterator<Item = &'a T>,
+) -> Result<Length> {
+    elements.try_fold(Length::zero(), |sum, element| sum + element.encoded_len()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SequenceOf;
+    use crate::{Decodable, Encodable, ErrorKind};
+
+    /// `SEQUENCE OF` three `INTEGER`s
+    const EXAMPLE: &[u8] = &[0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03];
+
+    #[test]
+    fn decode() {
+        let sequence_of = SequenceOf::<i8, 3>::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(sequence_of.len(), 3);
+
+        let mut elements = sequence_of.elements();
+        assert_eq!(elements.next(), Some(&1));
+        assert_eq!(elements.next(), Some(&2));
+        assert_eq!(elements.next(), Some(&3));
+        assert_eq!(elements.next(), None);
+    }
+
+    #[test]
+    fn encode() {
+        let mut sequence_of = SequenceOf::<i8, 3>::new();
+
+        for element in 1..=3 {
+            sequence_of.add(element).unwrap();
+        }
+
+        let mut buffer = [0u8; 16];
+        assert_eq!(EXAMPLE, sequence_of.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn reject_overlength() {
+        let err = SequenceOf::<i8, 2>::from_bytes(EXAMPLE).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+}
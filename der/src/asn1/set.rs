@@ -0,0 +1,233 @@
+//! ASN.1 `SET` and `SET OF` support.
+
+use crate::{
+    Any, ByteSlice, Decoder, Encodable, Error, ErrorKind, Length, Result, Tag, Tagged,
+};
+use core::{cmp::Ordering, convert::TryFrom};
+
+/// ASN.1 `SET` and `SET OF` types.
+///
+/// DER requires the elements of a `SET OF` to appear in ascending order of
+/// their encodings (X.690 §11.6). Decoding verifies this ordering and
+/// rejects out-of-order elements as [`ErrorKind::Noncanonical`]; to produce
+/// a sorted encoding from unsorted values, use [`Encoder::set`].
+///
+/// X.509 `RelativeDistinguishedName` is the most common example of a
+/// `SET OF` in practice.
+///
+/// [`Encoder::set`]: crate::Encoder::set
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Set<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> Set<'a> {
+    /// Create a new [`Set`] from a slice, verifying that its elements are
+    /// in ascending DER order.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        let mut decoder = Decoder::new(slice);
+        let mut last: Option<Any<'a>> = None;
+
+        while !decoder.is_finished() {
+            let any = decoder.any()?;
+
+            if let Some(last) = &last {
+                if ordering(last, &any)? == Ordering::Greater {
+                    return Err(ErrorKind::Noncanonical.into());
+                }
+            }
+
+            last = Some(any);
+        }
+
+        Self::new_unchecked(slice)
+    }
+
+    /// Create a new [`Set`] from a slice without verifying element order,
+    /// e.g. when [`DecodeOptions::accept_unsorted_sets`] is enabled.
+    ///
+    /// [`DecodeOptions::accept_unsorted_sets`]: crate::DecodeOptions::accept_unsorted_sets
+    pub(crate) fn new_unchecked(slice: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte sequence
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Decode values nested within a set, creating a new [`Decoder`] for
+    /// the data contained in the set's body and passing it to the provided
+    /// [`FnOnce`].
+    pub fn decode_nested<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let mut set_decoder = Decoder::new(self.as_bytes());
+        let result = f(&mut set_decoder)?;
+        set_decoder.finish(result)
+    }
+}
+
+/// Compare two elements of a set by their DER encodings.
+///
+/// Since no encoded tag is a prefix of another, minimal length octets
+/// order the same way as the lengths they encode, and both precede the
+/// contents, comparing the encoded tag octets, then the content length,
+/// then the content octets is equivalent to comparing the complete
+/// encodings as octet strings.
+fn ordering(a: &Any<'_>, b: &Any<'_>) -> Result<Ordering> {
+    // identifier octets are at most 3 bytes long
+    let mut buffer_a = [0u8; 3];
+    let mut buffer_b = [0u8; 3];
+    let tag_a = a.tag().encode_to_slice(&mut buffer_a)?;
+    let tag_b = b.tag().encode_to_slice(&mut buffer_b)?;
+
+    Ok(tag_a
+        .cmp(tag_b)
+        .then_with(|| a.as_bytes().len().cmp(&b.as_bytes().len()))
+        .then_with(|| a.as_bytes().cmp(b.as_bytes())))
+}
+
+/// Sort the concatenated DER encodings in the given buffer into ascending
+/// order, as required when encoding a `SET OF`.
+///
+/// Uses rotation-based exchanges to avoid allocating; sets are small in
+/// practice (e.g. X.509 RDNs typically hold a single attribute).
+pub(crate) fn sort_encoded(buf: &mut [u8]) -> Result<()> {
+    loop {
+        let mut swapped = false;
+        let mut offset = 0;
+
+        while offset < buf.len() {
+            let middle = offset + tlv_len(&buf[offset..])?;
+
+            if middle >= buf.len() {
+                break;
+            }
+
+            let end = middle + tlv_len(&buf[middle..])?;
+
+            if buf[offset..middle] > buf[middle..end] {
+                buf[offset..end].rotate_left(middle - offset);
+                swapped = true;
+                offset = end - (middle - offset);
+            } else {
+                offset = middle;
+            }
+        }
+
+        if !swapped {
+            return Ok(());
+        }
+    }
+}
+
+/// Get the complete encoded length (including tag and length octets) of
+/// the first TLV record in the given slice.
+fn tlv_len(bytes: &[u8]) -> Result<usize> {
+    let mut decoder = Decoder::new(bytes);
+    let any = decoder.any()?;
+    Ok(any.encoded_len()?.into())
+}
+
+impl AsRef<[u8]> for Set<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Set<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Set<'a>> {
+        any.tag().assert_eq(Tag::Set)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<Set<'a>> for Any<'a> {
+    fn from(set: Set<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::Set,
+            value: set.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for Set<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut crate::Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for Set<'a> {
+    const TAG: Tag = Tag::Set;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+    use crate::{Decodable, Encoder, ErrorKind};
+
+    /// `SET OF` two `INTEGER`s in ascending order
+    const EXAMPLE: &[u8] = &[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+
+    #[test]
+    fn decode() {
+        let set = Set::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(set.as_bytes(), &EXAMPLE[2..]);
+
+        let (first, second) = set
+            .decode_nested(|decoder| Ok((decoder.decode::<i8>()?, decoder.decode::<i8>()?)))
+            .unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(second, 2);
+    }
+
+    #[test]
+    fn reject_unordered() {
+        // same elements in descending order
+        let err = Set::from_bytes(&[0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+    }
+
+    #[test]
+    fn encode_sorts() {
+        let mut buffer = [0u8; 8];
+        let mut encoder = Encoder::new(&mut buffer);
+        encoder.set(&[&2i8, &1i8]).unwrap();
+        assert_eq!(encoder.finish().unwrap(), EXAMPLE);
+    }
+
+    #[test]
+    fn high_tag_number_round_trip() {
+        use crate::{Any, Tag, TagNumber};
+
+        // `[1000]` sorts after `[40]`: the initial tag octets tie, so the
+        // tag continuation octets must decide the order (a comparison by
+        // content length alone would order these the other way around)
+        let a = Any::new(Tag::context(TagNumber::new(1000), false), &[0x01]).unwrap();
+        let b = Any::new(Tag::context(TagNumber::new(40), false), &[0x01, 0x02]).unwrap();
+
+        let mut buffer = [0u8; 16];
+        let mut encoder = Encoder::new(&mut buffer);
+        encoder.set(&[&a, &b]).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        let set = Set::from_bytes(encoded).unwrap();
+        let decoded = set
+            .decode_nested(|decoder| Ok((decoder.any()?, decoder.any()?)))
+            .unwrap();
+        assert_eq!(decoded, (b, a));
+    }
+}
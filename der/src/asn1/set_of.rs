@@ -0,0 +1,171 @@
+//! ASN.1 `SET OF` fixed-capacity container.
+
+use crate::{
+    asn1::sequence_of::encoded_len_inner, Any, Decodable, Encodable, Encoder, Error, ErrorKind,
+    Header, Length, Result, Set, Tag, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `SET OF` backed by an array with a fixed upper bound `N` on the
+/// number of elements.
+///
+/// Decoding verifies the elements appear in the ascending order DER
+/// requires (via [`Set`]) and errors with [`ErrorKind::Overlength`] if the
+/// message contains more than `N` elements. Encoding sorts the elements
+/// into ascending order of their encodings, so they can be added in any
+/// order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetOf<T, const N: usize> {
+    /// Elements of the set
+    elements: [Option<T>; N],
+
+    /// Number of elements currently stored
+    length: usize,
+}
+
+impl<T, const N: usize> SetOf<T, N> {
+    /// Create a new [`SetOf`] containing no elements.
+    pub fn new() -> Self {
+        Self {
+            elements: [(); N].map(|_| None),
+            length: 0,
+        }
+    }
+
+    /// Add an element to this [`SetOf`], returning
+    /// [`ErrorKind::Overlength`] if the capacity `N` has been reached.
+    pub fn add(&mut self, element: T) -> Result<()> {
+        match self.elements.get_mut(self.length) {
+            Some(slot) => {
+                *slot = Some(element);
+                self.length += 1;
+                Ok(())
+            }
+            None => Err(ErrorKind::Overlength.into()),
+        }
+    }
+
+    /// Iterate over the elements of this [`SetOf`].
+    pub fn elements(&self) -> SetOfIter<'_, T> {
+        SetOfIter {
+            inner: self.elements[..self.length].iter(),
+        }
+    }
+
+    /// Number of elements in this [`SetOf`].
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Is this [`SetOf`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+impl<T, const N: usize> Default for SetOf<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the elements of a [`SetOf`].
+pub struct SetOfIter<'a, T> {
+    /// Inner iterator over the occupied storage slots
+    inner: core::slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for SetOfIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next().and_then(Option::as_ref)
+    }
+}
+
+impl<'a, T: Decodable<'a>, const N: usize> TryFrom<Any<'a>> for SetOf<T, N> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<SetOf<T, N>> {
+        Set::try_from(any)?.decode_nested(|decoder| {
+            let mut set_of = Self::new();
+
+            while !decoder.is_finished() {
+                set_of.add(decoder.decode()?)?;
+            }
+
+            Ok(set_of)
+        })
+    }
+}
+
+impl<T: Encodable, const N: usize> Encodable for SetOf<T, N> {
+    fn encoded_len(&self) -> Result<Length> {
+        let inner_len = encoded_len_inner(self.elements())?;
+        Header::new(Tag::Set, inner_len)?.encoded_len() + inner_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self.elements().next() {
+            Some(first) => {
+                let mut refs: [&dyn Encodable; N] = [first; N];
+
+                for (slot, element) in refs.iter_mut().zip(self.elements()) {
+                    *slot = element;
+                }
+
+                encoder.set(&refs[..self.length])
+            }
+            None => Header::new(Tag::Set, Length::zero())?.encode(encoder),
+        }
+    }
+}
+
+impl<T, const N: usize> Tagged for SetOf<T, N> {
+    const TAG: Tag = Tag::Set;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SetOf;
+    use crate::{Decodable, Encodable, ErrorKind};
+
+    /// `SET OF` two `INTEGER`s in ascending order
+    const EXAMPLE: &[u8] = &[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+
+    #[test]
+    fn decode() {
+        let set_of = SetOf::<i8, 2>::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(set_of.len(), 2);
+
+        let mut elements = set_of.elements();
+        assert_eq!(elements.next(), Some(&1));
+        assert_eq!(elements.next(), Some(&2));
+        assert_eq!(elements.next(), None);
+    }
+
+    #[test]
+    fn encode_sorts() {
+        let mut set_of = SetOf::<i8, 2>::new();
+        set_of.add(2).unwrap();
+        set_of.add(1).unwrap();
+
+        let mut buffer = [0u8; 16];
+        assert_eq!(EXAMPLE, set_of.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn reject_unordered() {
+        // same elements in descending order
+        let err = SetOf::<i8, 2>::from_bytes(&[0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+    }
+
+    #[test]
+    fn reject_overlength() {
+        let err = SetOf::<i8, 1>::from_bytes(EXAMPLE).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+}
@@ -0,0 +1,105 @@
+//! ASN.1 `TeletexString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `TeletexString` (a.k.a. `T61String`) type.
+///
+/// Still found in distinguished name attributes of old CA-issued
+/// certificates. The T.61 character set has been withdrawn and real-world
+/// values frequently contain Latin-1 or other encodings, so this type
+/// deliberately captures the raw bytes without attempting any charset
+/// validation or transcoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TeletexString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> TeletexString<'a> {
+    /// Create a new [`TeletexString`] from a byte slice.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw bytes of this [`TeletexString`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for TeletexString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&TeletexString<'a>> for TeletexString<'a> {
+    fn from(value: &TeletexString<'a>) -> TeletexString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for TeletexString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<TeletexString<'a>> {
+        any.tag().assert_eq(Tag::TeletexString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for Any<'a> {
+    fn from(teletex_string: TeletexString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::TeletexString,
+            value: teletex_string.inner,
+        }
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for &'a [u8] {
+    fn from(teletex_string: TeletexString<'a>) -> &'a [u8] {
+        teletex_string.as_bytes()
+    }
+}
+
+impl<'a> Encodable for TeletexString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for TeletexString<'a> {
+    const TAG: Tag = Tag::TeletexString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TeletexString;
+    use crate::{Decodable, Encodable};
+
+    /// A `TeletexString` containing a non-ASCII byte, as found in
+    /// legacy certificates
+    const EXAMPLE: &[u8] = &[0x14, 0x03, 0x41, 0xe9, 0x42];
+
+    #[test]
+    fn decode() {
+        let string = TeletexString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_bytes(), &[0x41, 0xe9, 0x42]);
+    }
+
+    #[test]
+    fn encode() {
+        let string = TeletexString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 5];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+}
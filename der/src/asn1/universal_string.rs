@@ -0,0 +1,102 @@
+//! ASN.1 `UniversalString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `UniversalString` type.
+///
+/// `UniversalString` is a sequence of 32-bit UCS-4 code units. This type
+/// captures the raw bytes without validation or transcoding; consumers
+/// which need characters can interpret the bytes themselves.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UniversalString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> UniversalString<'a> {
+    /// Create a new [`UniversalString`] from a byte slice.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw bytes of this [`UniversalString`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for UniversalString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&UniversalString<'a>> for UniversalString<'a> {
+    fn from(value: &UniversalString<'a>) -> UniversalString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for UniversalString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<UniversalString<'a>> {
+        any.tag().assert_eq(Tag::UniversalString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for Any<'a> {
+    fn from(universal_string: UniversalString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::UniversalString,
+            value: universal_string.inner,
+        }
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for &'a [u8] {
+    fn from(universal_string: UniversalString<'a>) -> &'a [u8] {
+        universal_string.as_bytes()
+    }
+}
+
+impl<'a> Encodable for UniversalString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for UniversalString<'a> {
+    const TAG: Tag = Tag::UniversalString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniversalString;
+    use crate::{Decodable, Encodable};
+
+    /// `A` encoded as a single UCS-4 code unit
+    const EXAMPLE: &[u8] = &[0x1c, 0x04, 0x00, 0x00, 0x00, 0x41];
+
+    #[test]
+    fn decode() {
+        let string = UniversalString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_bytes(), &[0x00, 0x00, 0x00, 0x41]);
+    }
+
+    #[test]
+    fn encode() {
+        let string = UniversalString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 6];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+}
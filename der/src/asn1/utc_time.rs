@@ -0,0 +1,247 @@
+//! ASN.1 `UTCTime` support.
+
+use crate::{
+    datetime::decode_decimal, Any, ByteSlice, DateTime, Encodable, Encoder, Error, ErrorKind,
+    GeneralizedTime, Length, Result, Tag, Tagged,
+};
+use core::{cmp::Ordering, convert::TryFrom};
+
+/// Length of a DER-encoded `UTCTime` value: `YYMMDDHHMMSSZ`
+const LENGTH: usize = 13;
+
+/// ASN.1 `UTCTime` type.
+///
+/// This type is used for X.509 certificate validity times prior to 2050.
+///
+/// DER requires the `YYMMDDHHMMSSZ` form: seconds are always present and
+/// the time is always expressed in UTC (trailing `Z`). Values using time
+/// zone offsets or omitting seconds are rejected.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UtcTime<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> UtcTime<'a> {
+    /// Create a new [`UtcTime`] from the given `YYMMDDHHMMSSZ` byte slice,
+    /// validating the component ranges.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.len() != LENGTH || bytes[LENGTH - 1] != b'Z' {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
+        let month = decode_decimal(bytes[2], bytes[3])?;
+        let day = decode_decimal(bytes[4], bytes[5])?;
+        let hour = decode_decimal(bytes[6], bytes[7])?;
+        let minute = decode_decimal(bytes[8], bytes[9])?;
+        let second = decode_decimal(bytes[10], bytes[11])?;
+
+        // also validates that the year digits are in fact digits
+        decode_decimal(bytes[0], bytes[1])?;
+
+        if (1..=12).contains(&month)
+            && (1..=31).contains(&day)
+            && hour <= 23
+            && minute <= 59
+            && second <= 59
+        {
+            Ok(Self {
+                inner: ByteSlice::new(bytes)?,
+            })
+        } else {
+            Err(ErrorKind::Value { tag: Self::TAG }.into())
+        }
+    }
+
+    /// Borrow the raw `YYMMDDHHMMSSZ` bytes of this [`UtcTime`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the year, interpreting the two-digit year according to
+    /// RFC 5280: values from 00 to 49 are in the 21st century, values
+    /// from 50 to 99 are in the 20th.
+    pub fn year(&self) -> u16 {
+        let yy = self.component(0) as u16;
+        if yy < 50 {
+            2000 + yy
+        } else {
+            1900 + yy
+        }
+    }
+
+    /// Get the month of the year (1-12)
+    pub fn month(&self) -> u8 {
+        self.component(2)
+    }
+
+    /// Get the day of the month (1-31)
+    pub fn day(&self) -> u8 {
+        self.component(4)
+    }
+
+    /// Get the hour of the day (0-23)
+    pub fn hour(&self) -> u8 {
+        self.component(6)
+    }
+
+    /// Get the minute of the hour (0-59)
+    pub fn minutes(&self) -> u8 {
+        self.component(8)
+    }
+
+    /// Get the second of the minute (0-59)
+    pub fn seconds(&self) -> u8 {
+        self.component(10)
+    }
+
+    /// Get the date and time this [`UtcTime`] represents as a [`DateTime`].
+    pub fn datetime(&self) -> DateTime {
+        // Components were validated by `UtcTime::new`
+        DateTime::new_unchecked(
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minutes(),
+            self.seconds(),
+        )
+    }
+
+    /// Decode the two-digit decimal component at the given offset.
+    ///
+    /// Validity of the digits is checked by [`UtcTime::new`].
+    fn component(&self, offset: usize) -> u8 {
+        let bytes = self.as_bytes();
+        (bytes[offset] - b'0') * 10 + (bytes[offset + 1] - b'0')
+    }
+}
+
+impl Ord for UtcTime<'_> {
+    /// Chronological ordering, so validity-window checks like
+    /// `not_before <= now` can be written directly against decoded values.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.datetime().cmp(&other.datetime())
+    }
+}
+
+impl PartialOrd for UtcTime<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<GeneralizedTime<'_>> for UtcTime<'_> {
+    fn eq(&self, other: &GeneralizedTime<'_>) -> bool {
+        self.datetime() == other.datetime()
+    }
+}
+
+impl PartialOrd<GeneralizedTime<'_>> for UtcTime<'_> {
+    /// Chronological comparison against a [`GeneralizedTime`], e.g. for
+    /// X.509 validity windows which mix the two types across the 2050
+    /// boundary. Fractional seconds on the [`GeneralizedTime`] are ignored.
+    fn partial_cmp(&self, other: &GeneralizedTime<'_>) -> Option<Ordering> {
+        Some(self.datetime().cmp(&other.datetime()))
+    }
+}
+
+impl AsRef<[u8]> for UtcTime<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&UtcTime<'a>> for UtcTime<'a> {
+    fn from(value: &UtcTime<'a>) -> UtcTime<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for UtcTime<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<UtcTime<'a>> {
+        any.tag().assert_eq(Tag::UtcTime)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<UtcTime<'a>> for Any<'a> {
+    fn from(utc_time: UtcTime<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::UtcTime,
+            value: utc_time.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for UtcTime<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for UtcTime<'a> {
+    const TAG: Tag = Tag::UtcTime;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UtcTime;
+    use crate::{Decodable, Encodable};
+
+    /// Validity `notBefore` time from the RSA-2048 PKCS#8 example
+    /// certificate: `Dec 21 21:31:10 2020 GMT`
+    const EXAMPLE: &[u8] = &[
+        0x17, 0x0d, 0x32, 0x30, 0x31, 0x32, 0x32, 0x31, 0x32, 0x31, 0x33, 0x31, 0x31, 0x30, 0x5a,
+    ];
+
+    #[test]
+    fn decode() {
+        let time = UtcTime::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(time.as_bytes(), b"201221213110Z");
+        assert_eq!(time.year(), 2020);
+        assert_eq!(time.month(), 12);
+        assert_eq!(time.day(), 21);
+        assert_eq!(time.hour(), 21);
+        assert_eq!(time.minutes(), 31);
+        assert_eq!(time.seconds(), 10);
+    }
+
+    #[test]
+    fn encode() {
+        let time = UtcTime::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 16];
+        let encoded = time.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn ordering() {
+        let not_before = UtcTime::new(b"201221213110Z").unwrap();
+        let not_after = UtcTime::new(b"211221213110Z").unwrap();
+        let now = UtcTime::new(b"210601000000Z").unwrap();
+        assert!(not_before <= now && now <= not_after);
+
+        // two-digit years are compared according to the RFC 5280 mapping
+        let last_century = UtcTime::new(b"991231235959Z").unwrap();
+        assert!(last_century < not_before);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // missing trailing `Z`
+        assert!(UtcTime::new(b"201221213110+").is_err());
+        // time zone offset instead of `Z`
+        assert!(UtcTime::new(b"2012212131+0000").is_err());
+        // month out of range
+        assert!(UtcTime::new(b"201321213110Z").is_err());
+        // non-digit component
+        assert!(UtcTime::new(b"2012212131X0Z").is_err());
+    }
+}
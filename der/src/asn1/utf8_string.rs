@@ -0,0 +1,228 @@
+//! ASN.1 `UTF8String` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::{convert::TryFrom, fmt, str};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// ASN.1 `UTF8String` type.
+///
+/// The preferred string type for modern X.509 and PKCS structures.
+/// Values which are not valid UTF-8 are rejected with
+/// [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Utf8String<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> Utf8String<'a> {
+    /// Create a new [`Utf8String`] from a byte slice, validating that it
+    /// is well-formed UTF-8.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        if str::from_utf8(slice).is_err() {
+            return Err(ErrorKind::CharSet { tag: Self::TAG }.into());
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Borrow the inner value as a `str`.
+    #[allow(clippy::expect_used)]
+    pub fn as_str(&self) -> &'a str {
+        // Well-formedness was validated by `Utf8String::new`
+        str::from_utf8(self.as_bytes()).expect("Utf8String UTF-8 invariant violated")
+    }
+}
+
+impl AsRef<[u8]> for Utf8String<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for Utf8String<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&Utf8String<'a>> for Utf8String<'a> {
+    fn from(value: &Utf8String<'a>) -> Utf8String<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Utf8String<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Utf8String<'a>> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Utf8String<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Utf8String<'a>> {
+        any.tag().assert_eq(Tag::Utf8String)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<Utf8String<'a>> for Any<'a> {
+    fn from(utf8_string: Utf8String<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::Utf8String,
+            value: utf8_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for Utf8String<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for Utf8String<'a> {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+impl Encodable for &str {
+    fn encoded_len(&self) -> Result<Length> {
+        Utf8String::try_from(*self)?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::try_from(*self)?.encode(encoder)
+    }
+}
+
+impl Tagged for &str {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<Any<'_>> for String {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<String> {
+        Utf8String::try_from(any).map(|s| s.as_str().into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Encodable for String {
+    fn encoded_len(&self) -> Result<Length> {
+        Utf8String::new(self.as_bytes())?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::new(self.as_bytes())?.encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for String {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+impl<'a> fmt::Display for Utf8String<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Utf8String<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> Deserialize<'de> for Utf8String<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let s = <&str>::deserialize(deserializer)?;
+        Self::new(s.as_bytes()).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Utf8String;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+
+    /// `München` encoded as a `UTF8String`
+    const EXAMPLE: &[u8] = &[
+        0x0c, 0x08, 0x4d, 0xc3, 0xbc, 0x6e, 0x63, 0x68, 0x65, 0x6e,
+    ];
+
+    #[test]
+    fn decode() {
+        let string = Utf8String::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_str(), "München");
+    }
+
+    #[test]
+    fn encode() {
+        let string = Utf8String::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 10];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn encode_str() {
+        let mut buffer = [0u8; 10];
+        let encoded = "München".encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_owned() {
+        let string = alloc::string::String::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string, "München");
+        assert_eq!(string.to_vec().unwrap(), EXAMPLE);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let string = Utf8String::new("Test User 1".as_bytes()).unwrap();
+        serde_test::assert_tokens(&string, &[serde_test::Token::BorrowedStr("Test User 1")]);
+    }
+
+    #[test]
+    fn reject_invalid_utf8() {
+        let err = Utf8String::new(&[0xC0]).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CharSet {
+                tag: Tag::Utf8String
+            }
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! ASN.1 `VideotexString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, Length, Result, Tag, Tagged};
+use core::convert::TryFrom;
+
+/// ASN.1 `VideotexString` type.
+///
+/// The videotex character sets (T.100/T.101) are long obsolete, so like
+/// [`TeletexString`][`crate::TeletexString`] this type captures the raw
+/// bytes without any charset validation or transcoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VideotexString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> VideotexString<'a> {
+    /// Create a new [`VideotexString`] from a byte slice.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the raw bytes of this [`VideotexString`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for VideotexString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&VideotexString<'a>> for VideotexString<'a> {
+    fn from(value: &VideotexString<'a>) -> VideotexString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for VideotexString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<VideotexString<'a>> {
+        any.tag().assert_eq(Tag::VideotexString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<VideotexString<'a>> for Any<'a> {
+    fn from(videotex_string: VideotexString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::VideotexString,
+            value: videotex_string.inner,
+        }
+    }
+}
+
+impl<'a> From<VideotexString<'a>> for &'a [u8] {
+    fn from(videotex_string: VideotexString<'a>) -> &'a [u8] {
+        videotex_string.as_bytes()
+    }
+}
+
+impl<'a> Encodable for VideotexString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for VideotexString<'a> {
+    const TAG: Tag = Tag::VideotexString;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VideotexString;
+    use crate::{Decodable, Encodable};
+
+    /// A `VideotexString` containing a non-ASCII byte
+    const EXAMPLE: &[u8] = &[0x15, 0x03, 0x41, 0xe9, 0x42];
+
+    #[test]
+    fn decode() {
+        let string = VideotexString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_bytes(), &[0x41, 0xe9, 0x42]);
+    }
+
+    #[test]
+    fn encode() {
+        let string = VideotexString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 5];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+}
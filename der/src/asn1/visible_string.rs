@@ -0,0 +1,142 @@
+//! ASN.1 `VisibleString` support.
+
+use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use core::{convert::TryFrom, fmt, str};
+
+/// ASN.1 `VisibleString` type.
+///
+/// The character set is restricted to the visible (printing) characters
+/// of ASCII plus space, i.e. `0x20` through `0x7E`. Values containing
+/// any other byte are rejected with [`ErrorKind::CharSet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VisibleString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> VisibleString<'a> {
+    /// Create a new [`VisibleString`] from a byte slice, validating the
+    /// character set.
+    pub fn new(slice: &'a [u8]) -> Result<Self> {
+        for &byte in slice {
+            if !(0x20..=0x7E).contains(&byte) {
+                return Err(ErrorKind::CharSet { tag: Self::TAG }.into());
+            }
+        }
+
+        Ok(Self {
+            inner: ByteSlice::new(slice)?,
+        })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Borrow the inner value as a `str`.
+    #[allow(clippy::expect_used)]
+    pub fn as_str(&self) -> &'a str {
+        // The character set validated by `VisibleString::new` is a
+        // subset of ASCII, so the bytes are always valid UTF-8
+        str::from_utf8(self.as_bytes()).expect("VisibleString charset invariant violated")
+    }
+}
+
+impl AsRef<[u8]> for VisibleString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for VisibleString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&VisibleString<'a>> for VisibleString<'a> {
+    fn from(value: &VisibleString<'a>) -> VisibleString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for VisibleString<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<VisibleString<'a>> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for VisibleString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<VisibleString<'a>> {
+        any.tag().assert_eq(Tag::VisibleString)?;
+        Self::new(any.as_bytes())
+    }
+}
+
+impl<'a> From<VisibleString<'a>> for Any<'a> {
+    fn from(visible_string: VisibleString<'a>) -> Any<'a> {
+        Any {
+            tag: Tag::VisibleString,
+            value: visible_string.inner,
+        }
+    }
+}
+
+impl<'a> Encodable for VisibleString<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::from(*self).encode(encoder)
+    }
+}
+
+impl<'a> Tagged for VisibleString<'a> {
+    const TAG: Tag = Tag::VisibleString;
+}
+
+impl<'a> fmt::Display for VisibleString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisibleString;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+
+    /// `abc!` encoded as a `VisibleString`
+    const EXAMPLE: &[u8] = &[0x1a, 0x04, 0x61, 0x62, 0x63, 0x21];
+
+    #[test]
+    fn decode() {
+        let string = VisibleString::from_bytes(EXAMPLE).unwrap();
+        assert_eq!(string.as_str(), "abc!");
+    }
+
+    #[test]
+    fn encode() {
+        let string = VisibleString::from_bytes(EXAMPLE).unwrap();
+        let mut buffer = [0u8; 6];
+        let encoded = string.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(EXAMPLE, encoded);
+    }
+
+    #[test]
+    fn reject_charset_violation() {
+        let err = VisibleString::new(b"tab\tseparated").err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CharSet {
+                tag: Tag::VisibleString
+            }
+        );
+    }
+}
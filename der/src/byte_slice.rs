@@ -1,14 +1,18 @@
 //! Common handling for types backed by byte slices with enforcement of a
 //! library-level length limitation i.e. `Length::max()`.
-//!
-//! This limit is presently 65,535 bytes.
 
-use crate::{Length, Result};
+use crate::{ErrorKind, Length, Result};
 use core::convert::TryFrom;
 
-/// Byte slice newtype which respects the `Length::max()` limit.
+/// Byte slice newtype which respects the [`Length::max`] limit.
+///
+/// All byte-slice-backed types in this library store their contents as a
+/// [`ByteSlice`], which enforces the maximum value length the DER
+/// [`Length`] type supports: presently 4,294,967,295 bytes (i.e.
+/// [`u32::MAX`]). Inputs which exceed the limit are rejected with
+/// [`ErrorKind::Oversized`], carrying the actual length for diagnosis.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) struct ByteSlice<'a> {
+pub struct ByteSlice<'a> {
     /// Inner value
     inner: &'a [u8],
 
@@ -18,11 +22,12 @@ pub(crate) struct ByteSlice<'a> {
 
 impl<'a> ByteSlice<'a> {
     /// Create a new [`ByteSlice`], ensuring that the provided `slice` value
-    /// is shorter than `Length::max()`.
+    /// is shorter than [`Length::max`].
     pub fn new(slice: &'a [u8]) -> Result<Self> {
         Ok(Self {
             inner: slice,
-            length: Length::try_from(slice.len())?,
+            length: Length::try_from(slice.len())
+                .map_err(|_| ErrorKind::Oversized { length: slice.len() })?,
         })
     }
 
@@ -47,3 +52,30 @@ impl AsRef<[u8]> for ByteSlice<'_> {
         self.as_bytes()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ByteSlice;
+    use crate::Length;
+
+    #[test]
+    fn new() {
+        let slice = ByteSlice::new(&[0x01, 0x02]).unwrap();
+        assert_eq!(slice.as_bytes(), &[0x01, 0x02]);
+        assert_eq!(slice.len(), Length::from(2u8));
+        assert!(!slice.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn oversized_error_display() {
+        use crate::ErrorKind;
+        use alloc::string::ToString;
+
+        let err = ErrorKind::Oversized { length: usize::MAX };
+        assert_eq!(
+            err.to_string(),
+            alloc::format!("input of {} bytes exceeds the DER length limit", usize::MAX)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Date/time representation shared by the ASN.1 time types.
+
+use crate::{ErrorKind, Result};
+use core::{
+    convert::TryFrom,
+    ops::{Add, Sub},
+    time::Duration,
+};
+
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(any(feature = "std", feature = "time"))]
+use crate::Error;
+
+/// Date-and-time type shared by the ASN.1 time types, e.g.
+/// [`GeneralizedTime`][`crate::GeneralizedTime`] and
+/// [`UtcTime`][`crate::UtcTime`].
+///
+/// This is a deliberately minimal calendar representation: components are
+/// range-checked individually but e.g. the number of days in a given month
+/// is not validated, matching the level of validation DER itself requires.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DateTime {
+    /// Full year, e.g. 2020
+    year: u16,
+
+    /// Month of the year (1-12)
+    month: u8,
+
+    /// Day of the month (1-31)
+    day: u8,
+
+    /// Hour of the day (0-23)
+    hour: u8,
+
+    /// Minute of the hour (0-59)
+    minutes: u8,
+
+    /// Second of the minute (0-59)
+    seconds: u8,
+}
+
+impl DateTime {
+    /// Create a new [`DateTime`] from the given UTC components, validating
+    /// their ranges.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minutes: u8, seconds: u8) -> Result<Self> {
+        if (1..=12).contains(&month)
+            && (1..=31).contains(&day)
+            && hour <= 23
+            && minutes <= 59
+            && seconds <= 59
+        {
+            Ok(Self {
+                year,
+                month,
+                day,
+                hour,
+                minutes,
+                seconds,
+            })
+        } else {
+            Err(ErrorKind::DateTime.into())
+        }
+    }
+
+    /// Create a new [`DateTime`] from components which have already been
+    /// range-validated, e.g. when decoding an ASN.1 time type whose
+    /// constructor performed the validation.
+    pub(crate) fn new_unchecked(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Get the year
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Get the month of the year (1-12)
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month (1-31)
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Get the hour of the day (0-23)
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Get the minute of the hour (0-59)
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    /// Get the second of the minute (0-59)
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Compute the [`Duration`] between the Unix epoch
+    /// (`1970-01-01T00:00:00Z`) and this [`DateTime`].
+    ///
+    /// Returns an error for dates before the epoch.
+    pub fn unix_duration(&self) -> Result<Duration> {
+        if self.year < 1970 {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        let days = days_from_civil(self.year as i64, self.month, self.day);
+        let secs = days * 86400
+            + (self.hour as i64) * 3600
+            + (self.minutes as i64) * 60
+            + (self.seconds as i64);
+
+        Ok(Duration::from_secs(secs as u64))
+    }
+
+    /// Create a [`DateTime`] from a [`Duration`] since the Unix epoch
+    /// (`1970-01-01T00:00:00Z`).
+    ///
+    /// Returns an error if the resulting year would overflow the supported
+    /// range. Sub-second precision is discarded.
+    pub fn from_unix_duration(duration: Duration) -> Result<Self> {
+        let secs = i64::try_from(duration.as_secs()).map_err(|_| ErrorKind::DateTime)?;
+        let days = secs.div_euclid(86400);
+        let secs_of_day = secs.rem_euclid(86400);
+
+        let (year, month, day) = civil_from_days(days);
+
+        if year < 0 || year > u16::MAX as i64 {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        DateTime::new(
+            year as u16,
+            month,
+            day,
+            (secs_of_day / 3600) as u8,
+            ((secs_of_day / 60) % 60) as u8,
+            (secs_of_day % 60) as u8,
+        )
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = Result<DateTime>;
+
+    /// Add the given [`Duration`], e.g. to compute the end of a validity
+    /// window. Sub-second precision is discarded; returns an error if the
+    /// result is unrepresentable.
+    fn add(self, rhs: Duration) -> Result<DateTime> {
+        let duration = self
+            .unix_duration()?
+            .checked_add(rhs)
+            .ok_or(ErrorKind::DateTime)?;
+
+        DateTime::from_unix_duration(duration)
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = Result<DateTime>;
+
+    /// Subtract the given [`Duration`]. Sub-second precision is discarded;
+    /// returns an error if the result would precede the Unix epoch.
+    fn sub(self, rhs: Duration) -> Result<DateTime> {
+        let duration = self
+            .unix_duration()?
+            .checked_sub(rhs)
+            .ok_or(ErrorKind::DateTime)?;
+
+        DateTime::from_unix_duration(duration)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<DateTime> for SystemTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<SystemTime> {
+        Ok(UNIX_EPOCH + datetime.unix_duration()?)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<SystemTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: SystemTime) -> Result<DateTime> {
+        let duration = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ErrorKind::DateTime)?;
+
+        DateTime::from_unix_duration(duration)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<DateTime> for time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<time::OffsetDateTime> {
+        let month = time::Month::try_from(datetime.month()).map_err(|_| ErrorKind::DateTime)?;
+
+        let date = time::Date::from_calendar_date(datetime.year() as i32, month, datetime.day())
+            .map_err(|_| ErrorKind::DateTime)?;
+
+        let time = time::Time::from_hms(datetime.hour(), datetime.minutes(), datetime.seconds())
+            .map_err(|_| ErrorKind::DateTime)?;
+
+        Ok(time::PrimitiveDateTime::new(date, time).assume_utc())
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<time::OffsetDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(datetime: time::OffsetDateTime) -> Result<DateTime> {
+        let datetime = datetime.to_offset(time::UtcOffset::UTC);
+        let year = u16::try_from(datetime.year()).map_err(|_| ErrorKind::DateTime)?;
+
+        DateTime::new(
+            year,
+            datetime.month() as u8,
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+        )
+    }
+}
+
+/// Compute the number of days since the Unix epoch for the given
+/// year/month/day in the proleptic Gregorian calendar.
+///
+/// Adapted from the "days from civil" algorithm by Howard Hinnant:
+/// <https://howardhinnant.github.io/date_algorithms.html>
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Compute the year/month/day for the given number of days since the Unix
+/// epoch; the inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { y + 1 } else { y };
+
+    (year, month as u8, day as u8)
+}
+
+/// Decode a 2-digit decimal component of an ASN.1 time value.
+pub(crate) fn decode_decimal(hi: u8, lo: u8) -> Result<u8> {
+    if hi.is_ascii_digit() && lo.is_ascii_digit() {
+        Ok((hi - b'0') * 10 + (lo - b'0'))
+    } else {
+        Err(ErrorKind::DateTime.into())
+    }
+}
+
+/// Encode a value from 0 to 99 as a 2-digit decimal component of an ASN.1
+/// time value.
+pub(crate) fn encode_decimal(bytes: &mut [u8], value: u8) {
+    bytes[0] = b'0' + (value / 10);
+    bytes[1] = b'0' + (value % 10);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTime;
+    use core::time::Duration;
+
+    #[test]
+    fn unix_duration_roundtrip() {
+        // `Dec 21 21:31:10 2020 GMT`
+        let datetime = DateTime::new(2020, 12, 21, 21, 31, 10).unwrap();
+        let duration = datetime.unix_duration().unwrap();
+        assert_eq!(duration.as_secs(), 1_608_586_270);
+        assert_eq!(DateTime::from_unix_duration(duration).unwrap(), datetime);
+
+        let epoch = DateTime::new(1970, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(epoch.unix_duration().unwrap(), Duration::from_secs(0));
+
+        // pre-epoch dates are rejected
+        let pre_epoch = DateTime::new(1969, 12, 31, 23, 59, 59).unwrap();
+        assert!(pre_epoch.unix_duration().is_err());
+    }
+
+    #[test]
+    fn duration_arithmetic() {
+        let not_before = DateTime::new(2020, 12, 21, 21, 31, 10).unwrap();
+        let not_after = (not_before + Duration::from_secs(365 * 86400)).unwrap();
+        assert_eq!(not_after, DateTime::new(2021, 12, 21, 21, 31, 10).unwrap());
+        assert_eq!((not_after - Duration::from_secs(365 * 86400)).unwrap(), not_before);
+        assert!(not_before < not_after);
+
+        // subtraction past the epoch is rejected
+        let epoch = DateTime::new(1970, 1, 1, 0, 0, 0).unwrap();
+        assert!((epoch - Duration::from_secs(1)).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time_conversions() {
+        use core::convert::TryFrom;
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let datetime = DateTime::new(2020, 12, 21, 21, 31, 10).unwrap();
+        let system_time = SystemTime::try_from(datetime).unwrap();
+        assert_eq!(
+            system_time,
+            UNIX_EPOCH + Duration::from_secs(1_608_586_270)
+        );
+        assert_eq!(DateTime::try_from(system_time).unwrap(), datetime);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_crate_conversions() {
+        use core::convert::TryFrom;
+
+        let datetime = DateTime::new(2020, 12, 21, 21, 31, 10).unwrap();
+        let odt = time::OffsetDateTime::try_from(datetime).unwrap();
+        assert_eq!(odt.unix_timestamp(), 1_608_586_270);
+        assert_eq!(DateTime::try_from(odt).unwrap(), datetime);
+    }
+}
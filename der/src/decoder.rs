@@ -1,11 +1,70 @@
 //! DER decoder.
 
-use crate::{Any, BitString, Decodable, ErrorKind, Length, Null, OctetString, Result, Sequence};
+use crate::{
+    Any, BitString, Choice, ContextSpecific, Decodable, Error, ErrorKind, GeneralizedTime, Header,
+    Ia5String, Length, Null, OctetString, PrintableString, Result, Sequence, Set, Tag, Tagged,
+    UtcTime, Utf8String,
+};
+use core::convert::TryFrom;
 use core::convert::TryInto;
 
+#[cfg(feature = "alloc")]
+use {crate::tag::CONSTRUCTED_FLAG, alloc::vec::Vec};
+
 #[cfg(feature = "oid")]
 use crate::ObjectIdentifier;
 
+/// Default maximum nesting depth for [`Decoder::sequence`] and
+/// [`Decoder::set`], deep enough for any PKIX structure.
+const DEFAULT_DEPTH_LIMIT: usize = 32;
+
+/// Length octet marking an indefinite-length BER encoding.
+pub(crate) const INDEFINITE_LENGTH_OCTET: u8 = 0x80;
+
+/// Encoding rules accepted by a [`Decoder`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum EncodingRules {
+    /// Distinguished Encoding Rules: definite lengths only (the default).
+    #[default]
+    Der,
+
+    /// Basic Encoding Rules: additionally accepts indefinite-length
+    /// encodings terminated by end-of-contents octets, as routinely
+    /// produced by streaming CMS/PKCS#7 implementations.
+    Ber,
+}
+
+/// Granular BER acceptance options.
+///
+/// Unlike the all-or-nothing [`EncodingRules`] switch, each option relaxes
+/// a single DER canonicality check, so interop with a specific broken
+/// producer can be enabled narrowly and audited.
+///
+/// Options not explicitly enabled default to strict DER behavior:
+///
+/// ```
+/// use der::DecodeOptions;
+///
+/// let options = DecodeOptions {
+///     accept_unsorted_sets: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeOptions {
+    /// Accept lengths which are not encoded in the minimal form, e.g.
+    /// `0x81 0x05` where DER requires `0x05`.
+    pub accept_non_minimal_lengths: bool,
+
+    /// Accept `BOOLEAN` values where `TRUE` is encoded as an octet other
+    /// than `0xFF`, when decoded via [`Decoder::boolean`].
+    pub accept_noncanonical_booleans: bool,
+
+    /// Accept `SET`/`SET OF` elements which are not in the ascending
+    /// order DER requires, when decoded via [`Decoder::set`].
+    pub accept_unsorted_sets: bool,
+}
+
 /// DER decoder.
 #[derive(Debug)]
 pub struct Decoder<'a> {
@@ -17,17 +76,77 @@ pub struct Decoder<'a> {
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Current nesting depth, i.e. the number of enclosing `SEQUENCE`/`SET`
+    /// bodies this decoder was created within.
+    depth: usize,
+
+    /// Maximum nesting depth before decoding fails with
+    /// [`ErrorKind::NestedTooDeep`].
+    depth_limit: usize,
+
+    /// Encoding rules this decoder accepts.
+    encoding_rules: EncodingRules,
+
+    /// Granular BER acceptance options.
+    options: DecodeOptions,
 }
 
 impl<'a> Decoder<'a> {
     /// Create a new decoder for the given byte slice.
     pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_depth_limit(bytes, DEFAULT_DEPTH_LIMIT)
+    }
+
+    /// Create a new BER-lenient decoder for the given byte slice, which
+    /// additionally accepts indefinite-length encodings.
+    ///
+    /// See [`EncodingRules::Ber`].
+    pub fn new_ber(bytes: &'a [u8]) -> Self {
+        Self {
+            encoding_rules: EncodingRules::Ber,
+            ..Self::new(bytes)
+        }
+    }
+
+    /// Create a new decoder for the given byte slice with a custom maximum
+    /// nesting depth.
+    ///
+    /// Deeply nested `SEQUENCE`s in attacker-controlled input can otherwise
+    /// exhaust the stack via recursion, so [`Decoder::new`] applies a
+    /// conservative default limit (currently 32).
+    pub fn with_depth_limit(bytes: &'a [u8], depth_limit: usize) -> Self {
         Self {
             bytes: Some(bytes),
             position: Length::zero(),
+            depth: 0,
+            depth_limit,
+            encoding_rules: EncodingRules::default(),
+            options: DecodeOptions::default(),
+        }
+    }
+
+    /// Create a new decoder for the given byte slice with the provided
+    /// granular BER acceptance options.
+    ///
+    /// See [`DecodeOptions`].
+    pub fn with_options(bytes: &'a [u8], options: DecodeOptions) -> Self {
+        Self {
+            options,
+            ..Self::new(bytes)
         }
     }
 
+    /// Get the [`EncodingRules`] this decoder accepts.
+    pub fn encoding_rules(&self) -> EncodingRules {
+        self.encoding_rules
+    }
+
+    /// Get the granular BER acceptance [`DecodeOptions`] for this decoder.
+    pub fn options(&self) -> DecodeOptions {
+        self.options
+    }
+
     /// Decode a value which impls the [`Decodable`] trait.
     pub fn decode<T: Decodable<'a>>(&mut self) -> Result<T> {
         if self.is_failed() {
@@ -36,10 +155,35 @@ impl<'a> Decoder<'a> {
 
         T::decode(self).map_err(|e| {
             self.bytes.take();
-            e.nested(self.position)
+
+            // Errors which occurred within this decoder already carry an
+            // absolute position; only annotate ones which don't.
+            if e.position().is_some() {
+                e
+            } else {
+                e.kind().at(self.position)
+            }
         })
     }
 
+    /// Decode a value which impls the [`Decodable`] trait, additionally
+    /// returning the exact TLV byte range it was decoded from.
+    ///
+    /// Use cases such as verifying the signature over an X.509
+    /// `tbsCertificate` require the original encoded bytes of a decoded
+    /// value; this captures them directly rather than re-encoding.
+    pub fn decode_with_raw<T: Decodable<'a>>(&mut self) -> Result<(T, &'a [u8])> {
+        let start = self.position;
+        let value = self.decode()?;
+
+        let raw = self
+            .bytes
+            .and_then(|bytes| bytes.get(start.into()..self.position.into()))
+            .ok_or_else(|| ErrorKind::Truncated.at(self.position))?;
+
+        Ok((value, raw))
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
@@ -86,6 +230,196 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `BOOLEAN`.
+    ///
+    /// When [`DecodeOptions::accept_noncanonical_booleans`] is enabled, any
+    /// nonzero contents octet is accepted as `TRUE`, rather than only the
+    /// canonical `0xFF`.
+    pub fn boolean(&mut self) -> Result<bool> {
+        if !self.options.accept_noncanonical_booleans {
+            return self.decode();
+        }
+
+        let any = self.any()?;
+        any.tag()
+            .assert_eq(Tag::Boolean)
+            .or_else(|e| self.error(e.kind()))?;
+
+        match any.as_bytes() {
+            [0x00] => Ok(false),
+            [_] => Ok(true),
+            _ => self.error(ErrorKind::Value { tag: Tag::Boolean }),
+        }
+    }
+
+    /// Attempt to decode an ASN.1 `BIT STRING` into an owned buffer,
+    /// reassembling constructed BER segments when in BER mode.
+    ///
+    /// Returns the number of unused bits in the final octet along with the
+    /// concatenated bit data. S/MIME and CMS content frequently arrives
+    /// chunked this way; strict DER mode continues to reject the
+    /// constructed form.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn ber_bit_string(&mut self) -> Result<(u8, Vec<u8>)> {
+        if self.peek() != Some(Tag::BitString.octet() | CONSTRUCTED_FLAG)
+            || self.encoding_rules != EncodingRules::Ber
+        {
+            return self
+                .bit_string()
+                .map(|string| (string.unused_bits(), string.as_bytes().to_vec()));
+        }
+
+        let mut unused_bits = 0;
+        let mut buffer = Vec::new();
+
+        self.ber_segments(Tag::BitString, &mut |segments| {
+            // only the final segment may have a partial last octet
+            if unused_bits != 0 {
+                return segments.error(ErrorKind::Value {
+                    tag: Tag::BitString,
+                });
+            }
+
+            let segment = segments.bit_string()?;
+            unused_bits = segment.unused_bits();
+            buffer.extend_from_slice(segment.as_bytes());
+            Ok(())
+        })?;
+
+        Ok((unused_bits, buffer))
+    }
+
+    /// Attempt to decode an ASN.1 `OCTET STRING` into an owned buffer,
+    /// reassembling constructed BER segments when in BER mode.
+    ///
+    /// Strict DER mode continues to reject the constructed form.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn ber_octet_string(&mut self) -> Result<Vec<u8>> {
+        if self.peek() != Some(Tag::OctetString.octet() | CONSTRUCTED_FLAG)
+            || self.encoding_rules != EncodingRules::Ber
+        {
+            return self.octet_string().map(|string| string.as_bytes().to_vec());
+        }
+
+        let mut buffer = Vec::new();
+
+        self.ber_segments(Tag::OctetString, &mut |segments| {
+            buffer.extend_from_slice(segments.octet_string()?.as_bytes());
+            Ok(())
+        })?;
+
+        Ok(buffer)
+    }
+
+    /// Walk the segments of a constructed BER string whose identifier
+    /// octet is at the cursor, calling `f` to decode each primitive
+    /// segment and recursing into nested constructed ones.
+    #[cfg(feature = "alloc")]
+    fn ber_segments<F>(&mut self, tag: Tag, f: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Decoder<'a>) -> Result<()>,
+    {
+        // consume the constructed identifier octet, then take the body
+        // per the definite or indefinite length which follows
+        self.byte()?;
+
+        let body = if self.peek() == Some(INDEFINITE_LENGTH_OCTET) {
+            self.byte()?;
+            self.indefinite_value()?
+        } else {
+            let length = Length::decode(self)?;
+            self.bytes(length)?
+        };
+
+        self.decode_nested(body, |segments| {
+            while !segments.is_finished() {
+                if segments.peek() == Some(tag.octet() | CONSTRUCTED_FLAG) {
+                    segments.ber_segments(tag, f)?;
+                } else {
+                    f(segments)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Attempt to decode an `EXPLICIT` context-specific field with the
+    /// provided tag number, returning `None` (without consuming any input)
+    /// if the next value in the message has a different tag.
+    pub fn context_specific(&mut self, tag_number: u16) -> Result<Option<Any<'a>>> {
+        if Tag::context_specific(tag_number).is_none() {
+            return self.error(ErrorKind::Overflow);
+        }
+
+        if self.peek_context_specific(tag_number) {
+            ContextSpecific::decode(self).map(|field| Some(field.value()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Attempt to decode an `IMPLICIT` context-specific field with the
+    /// provided tag number, re-tagging its contents as the given type.
+    ///
+    /// Returns `None` (without consuming any input) if the next value in
+    /// the message has a different tag.
+    pub fn context_specific_implicit<T>(&mut self, tag_number: u16) -> Result<Option<T>>
+    where
+        T: Tagged + TryFrom<Any<'a>, Error = Error>,
+    {
+        if Tag::context_specific(tag_number).is_none() {
+            return self.error(ErrorKind::Overflow);
+        }
+
+        // both the constructed and primitive forms are accepted, as
+        // `IMPLICIT` tagging preserves the form of the underlying type
+        if !self.peek_context_specific(tag_number) {
+            return Ok(None);
+        }
+
+        let any = Any::decode(self)?;
+        Any::new(T::TAG, any.as_bytes())
+            .and_then(T::try_from)
+            .map(Some)
+            .or_else(|e| self.error(e.kind()))
+    }
+
+    /// Attempt to decode a value which has a `DEFAULT` in its enclosing
+    /// schema, returning the provided default if it is absent.
+    ///
+    /// Fields equal to their default are omitted from DER messages (e.g.
+    /// X.509's `version DEFAULT v1`), so this checks whether the next
+    /// value in the message has a [`Tag`] the given type can decode
+    /// before consuming any input.
+    pub fn decode_with_default<T>(&mut self, default: T) -> Result<T>
+    where
+        T: Choice<'a>,
+    {
+        Ok(self.optional()?.unwrap_or(default))
+    }
+
+    /// Is the next value in the message a context-specific field with the
+    /// provided tag number (in either form)?
+    fn peek_context_specific(&self, tag_number: u16) -> bool {
+        matches!(
+            self.peek_tag(),
+            Some(Tag::ContextSpecific { number, .. }) if number.value() == tag_number
+        )
+    }
+
+    /// Attempt to decode an ASN.1 `GeneralizedTime`.
+    pub fn generalized_time(&mut self) -> Result<GeneralizedTime<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `IA5String`.
+    pub fn ia5_string(&mut self) -> Result<Ia5String<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `NULL` value.
     pub fn null(&mut self) -> Result<Null> {
         self.decode()
@@ -103,8 +437,67 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
-    /// Attempt to decode an ASN.1 `OPTIONAL` value.
-    pub fn optional<T: Decodable<'a>>(&mut self) -> Result<Option<T>> {
+    /// Attempt to decode an ASN.1 `OPTIONAL` value, checking whether the
+    /// next value in the message has a [`Tag`] the given [`Choice`] type
+    /// can decode before consuming any input.
+    pub fn optional<T: Choice<'a>>(&mut self) -> Result<Option<T>> {
+        match self.peek_tag() {
+            Some(tag) if T::can_decode(tag) => self.decode().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Peek at the next byte in the decoder without modifying the cursor.
+    pub fn peek(&self) -> Option<u8> {
+        self.remaining()
+            .ok()
+            .and_then(|bytes| bytes.first().cloned())
+    }
+
+    /// Peek at the next value's [`Header`] (i.e. its [`Tag`] and the length
+    /// of its value) without modifying the cursor.
+    ///
+    /// Returns `None` if the remaining data does not begin with a
+    /// well-formed header.
+    pub fn peek_header(&self) -> Option<Header> {
+        let mut decoder = Decoder::new(self.remaining().ok()?);
+        Header::decode(&mut decoder).ok()
+    }
+
+    /// Peek at the next value's [`Tag`] without modifying the cursor.
+    ///
+    /// This allows `CHOICE` dispatch and `OPTIONAL`/`DEFAULT` field logic
+    /// to branch on the upcoming tag before committing to a decode.
+    ///
+    /// Returns `None` if the remaining data does not begin with a
+    /// well-formed tag.
+    pub fn peek_tag(&self) -> Option<Tag> {
+        let mut decoder = Decoder::new(self.remaining().ok()?);
+        Tag::decode(&mut decoder).ok()
+    }
+
+    /// Get the position of the cursor within the byte slice being decoded,
+    /// i.e. the offset of the next value in the message.
+    ///
+    /// This can be used to record the byte offsets of decoded fields, e.g.
+    /// for error reporting or for locating the sub-range of a message a
+    /// signature was computed over.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Attempt to decode an ASN.1 `PrintableString`.
+    pub fn printable_string(&mut self) -> Result<PrintableString<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `UTCTime`.
+    pub fn utc_time(&mut self) -> Result<UtcTime<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `UTF8String`.
+    pub fn utf8_string(&mut self) -> Result<Utf8String<'a>> {
         self.decode()
     }
 
@@ -114,12 +507,147 @@ impl<'a> Decoder<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Sequence::decode(self)?.decode_nested(f).map_err(|e| {
-            self.bytes.take();
-            e.nested(self.position)
+        let sequence = Sequence::decode(self)?;
+        self.decode_nested(sequence.as_bytes(), f)
+    }
+
+    /// Decode a homogeneous `SEQUENCE OF` the given [`Decodable`] type,
+    /// appending each element to the provided storage.
+    ///
+    /// Any [`Extend`]-able collection works, e.g. `Vec` in alloc contexts
+    /// or fixed-capacity vectors (`heapless`, `arrayvec`) in heapless ones,
+    /// so list decoding shares one code path in both.
+    pub fn decode_sequence_of_into<T>(&mut self, out: &mut impl Extend<T>) -> Result<()>
+    where
+        T: Decodable<'a>,
+    {
+        self.sequence(|elements| {
+            while !elements.is_finished() {
+                let element = elements.decode()?;
+                out.extend(core::iter::once(element));
+            }
+
+            Ok(())
         })
     }
 
+    /// Attempt to decode an ASN.1 `SET` or `SET OF`, creating a new nested
+    /// [`Decoder`] and calling the provided argument with it.
+    ///
+    /// When [`DecodeOptions::accept_unsorted_sets`] is enabled, elements
+    /// which are not in the ascending order DER requires are accepted.
+    pub fn set<F, T>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let set = if self.options.accept_unsorted_sets {
+            let any = self.any()?;
+            any.tag()
+                .assert_eq(Tag::Set)
+                .or_else(|e| self.error(e.kind()))?;
+            Set::new_unchecked(any.as_bytes()).or_else(|e| self.error(e.kind()))?
+        } else {
+            Set::decode(self)?
+        };
+
+        self.decode_nested(set.as_bytes(), f)
+    }
+
+    /// Create a nested [`Decoder`] for the given value bytes, one level
+    /// deeper than this one, and call the provided argument with it.
+    ///
+    /// Returns [`ErrorKind::NestedTooDeep`] if the depth limit has been
+    /// reached.
+    fn decode_nested<F, T>(&mut self, bytes: &'a [u8], f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        if self.depth >= self.depth_limit {
+            return self.error(ErrorKind::NestedTooDeep);
+        }
+
+        // Offset of the nested value's body within the overall message: the
+        // nested bytes end at the current position, so subtract their length
+        let start = self
+            .position
+            .saturating_sub(Length::try_from(bytes.len()).unwrap_or_default());
+
+        let mut nested = Decoder {
+            bytes: Some(bytes),
+            position: Length::zero(),
+            depth: self.depth + 1,
+            depth_limit: self.depth_limit,
+            encoding_rules: self.encoding_rules,
+            options: self.options,
+        };
+
+        f(&mut nested)
+            .and_then(|result| nested.finish(result))
+            .map_err(|e| {
+                self.bytes.take();
+                e.nested(start)
+            })
+    }
+
+    /// Skip over the next value in the message without interpreting it,
+    /// returning its [`Header`].
+    ///
+    /// This enables forward-compatible parsers which tolerate unknown
+    /// elements (e.g. extensions or attributes added by newer versions of
+    /// a schema) inside a `SEQUENCE` rather than failing hard.
+    pub fn skip_value(&mut self) -> Result<Header> {
+        let header = Header::decode(self)?;
+        self.bytes(header.length)?;
+        Ok(header)
+    }
+
+    /// Consume the value of an indefinite-length encoded TLV whose header
+    /// has already been read, returning the bytes up to (but not
+    /// including) the matching end-of-contents octets.
+    ///
+    /// The scan tracks the nesting of inner indefinite-length encodings so
+    /// end-of-contents octets terminating an inner value are not mistaken
+    /// for the outer one's, and skips over definite-length values without
+    /// interpreting them.
+    pub(crate) fn indefinite_value(&mut self) -> Result<&'a [u8]> {
+        let bytes = self.remaining()?;
+        let mut depth = 1usize;
+        let mut offset = 0usize;
+
+        while depth > 0 {
+            let remaining = match bytes.get(offset..) {
+                Some(remaining) if !remaining.is_empty() => remaining,
+                _ => return self.error(ErrorKind::Truncated),
+            };
+
+            if remaining.starts_with(&[0x00, 0x00]) {
+                depth -= 1;
+                offset += 2;
+                continue;
+            }
+
+            let mut header = Decoder::new(remaining);
+            let tag = Tag::decode(&mut header)?;
+
+            if header.peek() == Some(INDEFINITE_LENGTH_OCTET) {
+                if !tag.is_constructed() {
+                    return self.error(ErrorKind::Length { tag });
+                }
+
+                depth += 1;
+                offset += header.position().to_usize() + 1;
+            } else {
+                let length = Length::decode(&mut header)?;
+                offset += header.position().to_usize() + length.to_usize();
+            }
+        }
+
+        // consume the value and the end-of-contents octets, returning
+        // only the former
+        let value = self.bytes(offset)?;
+        Ok(&value[..offset.saturating_sub(2)])
+    }
+
     /// Decode a single byte, updating the internal cursor.
     pub(crate) fn byte(&mut self) -> Result<u8> {
         match self.bytes(1u8)? {
@@ -155,8 +683,11 @@ impl<'a> Decoder<'a> {
             .ok_or_else(|| ErrorKind::Truncated.at(self.position))
     }
 
-    /// Get the number of bytes still remaining in the buffer.
-    fn remaining_len(&self) -> Result<Length> {
+    /// Get the number of bytes still remaining in the buffer, allowing
+    /// unconsumed trailing data to be detected explicitly.
+    ///
+    /// Returns an error if the decoder is in a failed state.
+    pub fn remaining_len(&self) -> Result<Length> {
         self.remaining()?.len().try_into()
     }
 }
@@ -170,7 +701,282 @@ impl<'a> From<&'a [u8]> for Decoder<'a> {
 #[cfg(test)]
 mod tests {
     use super::Decoder;
-    use crate::{Decodable, ErrorKind, Length, Tag};
+    use crate::{Choice, Decodable, ErrorKind, Length, Result, Tag};
+
+    /// `CHOICE { flag BOOLEAN, count INTEGER }`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    enum FlagOrCount {
+        Flag(bool),
+        Count(i8),
+    }
+
+    impl<'a> Decodable<'a> for FlagOrCount {
+        fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+            if decoder.peek() == Some(Tag::Boolean.octet()) {
+                decoder.decode().map(Self::Flag)
+            } else {
+                decoder.decode().map(Self::Count)
+            }
+        }
+    }
+
+    impl Choice<'_> for FlagOrCount {
+        fn can_decode(tag: Tag) -> bool {
+            matches!(tag, Tag::Boolean | Tag::Integer)
+        }
+    }
+
+    #[test]
+    fn choice() {
+        let mut decoder = Decoder::new(&[0x01, 0x01, 0xFF, 0x02, 0x01, 0x2A]);
+        assert_eq!(decoder.decode::<FlagOrCount>().unwrap(), FlagOrCount::Flag(true));
+        assert_eq!(
+            decoder.decode::<FlagOrCount>().unwrap(),
+            FlagOrCount::Count(42)
+        );
+    }
+
+    #[test]
+    fn optional_skips_other_tags() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A]);
+
+        // no BOOLEAN at the cursor, so nothing is consumed
+        assert_eq!(decoder.optional::<bool>().unwrap(), None);
+        assert_eq!(decoder.optional::<i8>().unwrap(), Some(42));
+        assert_eq!(decoder.optional::<i8>().unwrap(), None);
+    }
+
+    #[test]
+    fn default_fields() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A]);
+
+        // `BOOLEAN DEFAULT TRUE` absent from the message
+        assert!(decoder.decode_with_default(true).unwrap());
+        assert_eq!(decoder.decode_with_default(7i8).unwrap(), 42);
+    }
+
+    #[test]
+    fn peek_tag_and_header() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A]);
+        assert_eq!(decoder.peek_tag(), Some(Tag::Integer));
+
+        let header = decoder.peek_header().unwrap();
+        assert_eq!(header.tag, Tag::Integer);
+        assert_eq!(header.length, Length::from(1u8));
+
+        // peeking does not advance the cursor
+        assert_eq!(decoder.decode::<i8>().unwrap(), 42);
+        assert_eq!(decoder.peek_tag(), None);
+        assert!(decoder.peek_header().is_none());
+    }
+
+    /// Decode nested `SEQUENCE`s all the way down, returning the depth
+    fn nesting_depth(decoder: &mut Decoder<'_>) -> Result<usize> {
+        if decoder.is_finished() {
+            Ok(0)
+        } else {
+            decoder.sequence(nesting_depth).map(|depth| depth + 1)
+        }
+    }
+
+    /// Serialize `depth` nested empty `SEQUENCE`s into `buffer`
+    fn nested_sequences(depth: usize, buffer: &mut [u8]) -> &[u8] {
+        let len = depth * 2;
+
+        for (i, chunk) in buffer[..len].chunks_exact_mut(2).enumerate() {
+            chunk[0] = 0x30;
+            chunk[1] = (len - (i + 1) * 2) as u8;
+        }
+
+        &buffer[..len]
+    }
+
+    #[test]
+    fn depth_limit() {
+        let mut buffer = [0u8; 128];
+        let bytes = nested_sequences(32, &mut buffer);
+        let mut decoder = Decoder::new(bytes);
+        assert_eq!(nesting_depth(&mut decoder).unwrap(), 32);
+
+        let mut buffer = [0u8; 128];
+        let bytes = nested_sequences(33, &mut buffer);
+        let mut decoder = Decoder::new(bytes);
+        let err = nesting_depth(&mut decoder).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::NestedTooDeep);
+
+        let mut decoder = Decoder::with_depth_limit(bytes, 64);
+        assert_eq!(nesting_depth(&mut decoder).unwrap(), 33);
+    }
+
+    #[test]
+    fn ber_indefinite_length() {
+        // SEQUENCE (indefinite) { INTEGER 42, SEQUENCE (indefinite) { BOOLEAN TRUE } }
+        let bytes = &[
+            0x30, 0x80, 0x02, 0x01, 0x2A, 0x30, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut decoder = Decoder::new_ber(bytes);
+        let (count, flag) = decoder
+            .sequence(|nested| {
+                let count = nested.decode::<i8>()?;
+                let flag = nested.sequence(|inner| inner.decode::<bool>())?;
+                Ok((count, flag))
+            })
+            .unwrap();
+        assert_eq!(count, 42);
+        assert!(flag);
+        assert!(decoder.is_finished());
+
+        // strict DER mode continues to reject indefinite lengths
+        let mut decoder = Decoder::new(bytes);
+        assert!(decoder.sequence(|nested| nested.decode::<i8>()).is_err());
+
+        // indefinite lengths are only valid for constructed values
+        let mut decoder = Decoder::new_ber(&[0x02, 0x80, 0x2A, 0x00, 0x00]);
+        assert!(decoder.decode::<i8>().is_err());
+
+        // a missing end-of-contents marker is truncation
+        let mut decoder = Decoder::new_ber(&[0x30, 0x80, 0x02, 0x01, 0x2A]);
+        assert!(decoder.sequence(|nested| nested.decode::<i8>()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ber_constructed_strings() {
+        // constructed OCTET STRING with two primitive segments
+        let bytes = &[
+            0x24, 0x08, 0x04, 0x02, 0xAA, 0xBB, 0x04, 0x02, 0xCC, 0xDD,
+        ];
+        let mut decoder = Decoder::new_ber(bytes);
+        assert_eq!(
+            decoder.ber_octet_string().unwrap(),
+            &[0xAA, 0xBB, 0xCC, 0xDD]
+        );
+        assert!(decoder.is_finished());
+
+        // ...rejected in strict DER mode
+        assert!(Decoder::new(bytes).ber_octet_string().is_err());
+
+        // indefinite-length constructed OCTET STRING
+        let mut decoder = Decoder::new_ber(&[0x24, 0x80, 0x04, 0x01, 0xAA, 0x00, 0x00]);
+        assert_eq!(decoder.ber_octet_string().unwrap(), &[0xAA]);
+
+        // primitive strings decode as usual in either mode
+        let mut decoder = Decoder::new_ber(&[0x04, 0x02, 0xAA, 0xBB]);
+        assert_eq!(decoder.ber_octet_string().unwrap(), &[0xAA, 0xBB]);
+
+        // constructed BIT STRING: unused bits come from the final segment
+        let mut decoder = Decoder::new_ber(&[
+            0x23, 0x09, 0x03, 0x02, 0x00, 0xAA, 0x03, 0x03, 0x04, 0xBB, 0xC0,
+        ]);
+        assert_eq!(
+            decoder.ber_bit_string().unwrap(),
+            (4, alloc::vec![0xAA, 0xBB, 0xC0])
+        );
+
+        // non-final segments must not have unused bits
+        let mut decoder = Decoder::new_ber(&[
+            0x23, 0x09, 0x03, 0x03, 0x04, 0xAA, 0xB0, 0x03, 0x02, 0x00, 0xCC,
+        ]);
+        assert!(decoder.ber_bit_string().is_err());
+    }
+
+    #[test]
+    fn accept_non_minimal_lengths() {
+        use super::DecodeOptions;
+
+        // INTEGER with a non-minimal long-form length
+        let bytes = &[0x02, 0x81, 0x01, 0x2A];
+        assert!(Decoder::new(bytes).decode::<i8>().is_err());
+
+        let options = DecodeOptions {
+            accept_non_minimal_lengths: true,
+            ..Default::default()
+        };
+        assert_eq!(Decoder::with_options(bytes, options).decode::<i8>().unwrap(), 42);
+    }
+
+    #[test]
+    fn accept_noncanonical_booleans() {
+        use super::DecodeOptions;
+
+        // BOOLEAN TRUE encoded as 0x01 rather than 0xFF
+        let bytes = &[0x01, 0x01, 0x01];
+        assert!(Decoder::new(bytes).boolean().is_err());
+
+        let options = DecodeOptions {
+            accept_noncanonical_booleans: true,
+            ..Default::default()
+        };
+        assert!(Decoder::with_options(bytes, options).boolean().unwrap());
+
+        // canonical values still decode, and other tags are still rejected
+        assert!(!Decoder::with_options(&[0x01, 0x01, 0x00], options)
+            .boolean()
+            .unwrap());
+        assert!(Decoder::with_options(&[0x02, 0x01, 0x01], options)
+            .boolean()
+            .is_err());
+    }
+
+    #[test]
+    fn accept_unsorted_sets() {
+        use super::DecodeOptions;
+
+        // SET OF two INTEGERs in descending order
+        let bytes = &[0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01];
+        let decode_pair = |decoder: &mut Decoder<'_>| {
+            decoder.set(|elements| Ok((elements.decode::<i8>()?, elements.decode::<i8>()?)))
+        };
+
+        assert!(decode_pair(&mut Decoder::new(bytes)).is_err());
+
+        let options = DecodeOptions {
+            accept_unsorted_sets: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            decode_pair(&mut Decoder::with_options(bytes, options)).unwrap(),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    fn decode_with_raw_bytes() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+
+        let (value, raw) = decoder.decode_with_raw::<i8>().unwrap();
+        assert_eq!(value, 42);
+        assert_eq!(raw, &[0x02, 0x01, 0x2A]);
+
+        let (value, raw) = decoder.decode_with_raw::<bool>().unwrap();
+        assert!(value);
+        assert_eq!(raw, &[0x01, 0x01, 0xFF]);
+    }
+
+    #[test]
+    fn position_introspection() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+        assert_eq!(decoder.position(), Length::zero());
+        assert_eq!(decoder.remaining_len().unwrap(), Length::from(6u8));
+
+        assert_eq!(decoder.decode::<i8>().unwrap(), 42);
+        assert_eq!(decoder.position(), Length::from(3u8));
+        assert_eq!(decoder.remaining_len().unwrap(), Length::from(3u8));
+    }
+
+    #[test]
+    fn skip_unknown_values() {
+        let mut decoder = Decoder::new(&[0xA0, 0x03, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF]);
+
+        let header = decoder.skip_value().unwrap();
+        assert_eq!(header.length, Length::from(3u8));
+        assert!(decoder.decode::<bool>().unwrap());
+
+        // skipping still requires the value to be complete
+        let mut decoder = Decoder::new(&[0x04, 0x03, 0x01]);
+        assert!(decoder.skip_value().is_err());
+    }
 
     #[test]
     fn truncated_message() {
@@ -204,4 +1010,56 @@ mod tests {
         );
         assert_eq!(Some(Length::from(3u8)), err.position());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_sequence_of_into_vec() {
+        use alloc::vec::Vec;
+
+        // `SEQUENCE OF` three `INTEGER`s
+        let mut decoder = Decoder::new(&[
+            0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03,
+        ]);
+
+        let mut values = Vec::new();
+        decoder.decode_sequence_of_into::<i8>(&mut values).unwrap();
+        assert_eq!(values, &[1, 2, 3]);
+
+        // malformed element surfaces as an error
+        let mut decoder = Decoder::new(&[0x30, 0x02, 0x02, 0x05]);
+        let mut values = Vec::new();
+        assert!(decoder.decode_sequence_of_into::<i8>(&mut values).is_err());
+    }
+
+    #[test]
+    fn nested_error_positions() {
+        // SEQUENCE containing a truncated INTEGER after a BOOLEAN
+        let mut decoder = Decoder::new(&[0x30, 0x05, 0x01, 0x01, 0xFF, 0x02, 0x02]);
+
+        let err = decoder
+            .sequence(|nested| {
+                nested.decode::<bool>()?;
+                nested.decode::<i8>()
+            })
+            .err()
+            .unwrap();
+
+        // The error is reported at the position within the outer message,
+        // offset by the nested decoder's starting position
+        assert_eq!(ErrorKind::Length { tag: Tag::Integer }, err.kind());
+        assert_eq!(Some(Length::from(7u8)), err.position());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn error_display_includes_position() {
+        use alloc::string::ToString;
+
+        let mut decoder = Decoder::new(&[0x02, 0x01]);
+        let err = i8::decode(&mut decoder).err().unwrap();
+        assert_eq!(
+            "incorrect length for INTEGER at DER byte 2",
+            err.to_string()
+        );
+    }
 }
@@ -0,0 +1,162 @@
+//! Debug dump of DER documents as an indented tree.
+
+use crate::{oid::db, Any, Decoder, ErrorKind, Result, Tag};
+use core::fmt;
+
+/// Maximum number of value bytes included in a primitive preview.
+const PREVIEW_LIMIT: usize = 16;
+
+/// Maximum nesting depth, matching [`Decoder`]'s default limit so deeply
+/// nested inputs cannot exhaust the stack.
+const DEPTH_LIMIT: usize = 32;
+
+/// Render the TLV structure of the given DER document as an indented tree,
+/// without requiring knowledge of its ASN.1 module.
+///
+/// Constructed values are recursed into; primitive values are followed by a
+/// short preview of their contents, with strings rendered as text and
+/// `OBJECT IDENTIFIER`s resolved to their names via the
+/// [`oid::db`][`crate::oid::db`] constants where possible.
+///
+/// ```
+/// /// `AlgorithmIdentifier` for `rsaEncryption`
+/// const EXAMPLE: &[u8] = &[
+///     0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01,
+///     0x01, 0x01, 0x05, 0x00,
+/// ];
+///
+/// let mut tree = String::new();
+/// der::dump(EXAMPLE, &mut tree).unwrap();
+/// assert_eq!(
+///     tree,
+///     "SEQUENCE (13 bytes)\n\
+///      \x20 OBJECT IDENTIFIER (9 bytes): 1.2.840.113549.1.1.1 (rsaEncryption)\n\
+///      \x20 NULL (0 bytes)\n"
+/// );
+/// ```
+pub fn dump<W: fmt::Write>(input: &[u8], out: &mut W) -> Result<()> {
+    dump_body(input, out, 0)
+}
+
+/// Dump every TLV record in the given body at the given indentation depth.
+fn dump_body<W: fmt::Write>(body: &[u8], out: &mut W, depth: usize) -> Result<()> {
+    let mut decoder = Decoder::new(body);
+
+    while !decoder.is_finished() {
+        let any = decoder.decode::<Any<'_>>()?;
+        dump_value(any, out, depth)?;
+    }
+
+    Ok(())
+}
+
+/// Dump a single value, recursing if it's encoded in constructed form.
+fn dump_value<W: fmt::Write>(any: Any<'_>, out: &mut W, depth: usize) -> Result<()> {
+    for _ in 0..depth {
+        out.write_str("  ")?;
+    }
+
+    write!(out, "{} ({} bytes)", any.tag(), any.len())?;
+
+    if any.tag().is_constructed() {
+        if depth >= DEPTH_LIMIT {
+            return Err(ErrorKind::NestedTooDeep.into());
+        }
+
+        writeln!(out)?;
+        return dump_body(any.as_bytes(), out, depth + 1);
+    }
+
+    if !any.is_empty() {
+        dump_preview(any, out)?;
+    }
+
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Write a preview of a primitive value's contents.
+fn dump_preview<W: fmt::Write>(any: Any<'_>, out: &mut W) -> Result<()> {
+    match any.tag() {
+        Tag::ObjectIdentifier => {
+            let oid = any.oid()?;
+            write!(out, ": {}", oid)?;
+
+            if let Some(name) = db::name(&oid) {
+                write!(out, " ({})", name)?;
+            }
+        }
+        Tag::Utf8String
+        | Tag::NumericString
+        | Tag::PrintableString
+        | Tag::Ia5String
+        | Tag::VisibleString
+        | Tag::UtcTime
+        | Tag::GeneralizedTime => {
+            if let Ok(s) = core::str::from_utf8(any.as_bytes()) {
+                write!(out, ": \"{}\"", s)?;
+            }
+        }
+        _ => {
+            out.write_str(":")?;
+
+            for byte in any.as_bytes().iter().take(PREVIEW_LIMIT) {
+                write!(out, " {:02x}", byte)?;
+            }
+
+            if any.as_bytes().len() > PREVIEW_LIMIT {
+                out.write_str(" ...")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::String;
+
+    /// X.509 `Validity` with a `UTCTime` and a `GeneralizedTime` field
+    const VALIDITY_EXAMPLE: &[u8] = &[
+        0x30, 0x20, 0x17, 0x0d, 0x32, 0x30, 0x31, 0x32, 0x32, 0x31, 0x32, 0x31, 0x33, 0x31, 0x31,
+        0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x35, 0x30, 0x31, 0x32, 0x32, 0x31, 0x32, 0x31, 0x33,
+        0x31, 0x31, 0x30, 0x5a,
+    ];
+
+    #[test]
+    fn dump() {
+        let mut tree = String::new();
+        super::dump(VALIDITY_EXAMPLE, &mut tree).unwrap();
+
+        assert_eq!(
+            tree,
+            "SEQUENCE (32 bytes)\n\
+             \x20 UTCTime (13 bytes): \"201221213110Z\"\n\
+             \x20 GeneralizedTime (15 bytes): \"20501221213110Z\"\n"
+        );
+    }
+
+    #[test]
+    fn dump_rejects_malformed_input() {
+        // truncated SEQUENCE body
+        assert!(super::dump(&[0x30, 0x03, 0x02, 0x01], &mut String::new()).is_err());
+    }
+
+    #[test]
+    fn dump_rejects_excessive_nesting() {
+        // 64 nested empty SEQUENCEs: dumping must stop at the depth limit
+        // rather than recursing once per level
+        let mut input = [0u8; 128];
+        for (i, chunk) in input.chunks_exact_mut(2).enumerate() {
+            chunk[0] = 0x30;
+            chunk[1] = (126 - i * 2) as u8;
+        }
+
+        let err = super::dump(&input, &mut String::new()).err().unwrap();
+        assert_eq!(err.kind(), crate::ErrorKind::NestedTooDeep);
+
+        // nesting within the limit still dumps in full
+        assert!(super::dump(&input[96..], &mut String::new()).is_ok());
+    }
+}
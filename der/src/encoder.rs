@@ -1,20 +1,48 @@
 //! DER encoder.
 
 use crate::{
-    asn1::sequence, BitString, Encodable, ErrorKind, Header, Length, Null, OctetString, Result, Tag,
+    asn1::{sequence, set},
+    BitString, Encodable, ErrorKind, Header, Length, Null, OctetString, Result, Tag,
 };
 use core::convert::TryInto;
+use core::fmt;
 
 #[cfg(feature = "oid")]
 use crate::ObjectIdentifier;
 
+#[cfg(feature = "std")]
+use std::io;
+
+/// Output sink encoded data is written into.
+enum Sink<'a> {
+    /// Pre-sized byte slice
+    Slice(&'a mut [u8]),
+
+    /// [`std::io::Write`] stream
+    #[cfg(feature = "std")]
+    Writer(&'a mut dyn io::Write),
+}
+
+impl fmt::Debug for Sink<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sink::Slice(bytes) => f.debug_tuple("Slice").field(&bytes.len()).finish(),
+            #[cfg(feature = "std")]
+            Sink::Writer(_) => f.debug_tuple("Writer").finish(),
+        }
+    }
+}
+
 /// DER encoder.
 #[derive(Debug)]
 pub struct Encoder<'a> {
-    /// Buffer into which DER-encoded message is written
-    bytes: Option<&'a mut [u8]>,
+    /// Sink into which the DER-encoded message is written.
+    ///
+    /// In the event an error was previously encountered this will be set to
+    /// `None` to prevent further encoding while in a bad state.
+    sink: Option<Sink<'a>>,
 
-    /// Total number of bytes written to buffer so far
+    /// Total number of bytes written to the sink so far
     position: Length,
 }
 
@@ -22,7 +50,23 @@ impl<'a> Encoder<'a> {
     /// Create a new encoder with the given byte slice as a backing buffer.
     pub fn new(bytes: &'a mut [u8]) -> Self {
         Self {
-            bytes: Some(bytes),
+            sink: Some(Sink::Slice(bytes)),
+            position: Length::zero(),
+        }
+    }
+
+    /// Create a new encoder which streams its output into the given
+    /// [`std::io::Write`], e.g. a file or socket, instead of requiring a
+    /// pre-sized buffer.
+    ///
+    /// Note that [`Encoder::finish`] is only meaningful for slice-backed
+    /// encoders; see [`Encodable::encode_to_writer`] for the usual entry
+    /// point to streaming encoding.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn new_writer(writer: &'a mut dyn io::Write) -> Self {
+        Self {
+            sink: Some(Sink::Writer(writer)),
             position: Length::zero(),
         }
     }
@@ -34,32 +78,54 @@ impl<'a> Encoder<'a> {
         }
 
         encodable.encode(self).map_err(|e| {
-            self.bytes.take();
+            self.sink.take();
             e.nested(self.position)
         })
     }
 
+    /// Encode a value which has a `DEFAULT` in its enclosing schema,
+    /// omitting it entirely if it is equal to the default value (as DER
+    /// requires).
+    ///
+    /// The decode-side counterpart is
+    /// [`Decoder::decode_with_default`][`crate::Decoder::decode_with_default`].
+    pub fn encode_with_default<T>(&mut self, value: &T, default: &T) -> Result<()>
+    where
+        T: Encodable + PartialEq,
+    {
+        if value == default {
+            Ok(())
+        } else {
+            self.encode(value)
+        }
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
-        self.bytes.take();
+        self.sink.take();
         Err(kind.at(self.position))
     }
 
     /// Did the decoding operation fail due to an error?
     pub fn is_failed(&self) -> bool {
-        self.bytes.is_none()
+        self.sink.is_none()
     }
 
     /// Finish encoding to the buffer, returning a slice containing the data
     /// written to the buffer.
+    ///
+    /// Only meaningful for slice-backed encoders; streaming encoders
+    /// created with [`Encoder::new_writer`] return an error.
     pub fn finish(self) -> Result<&'a [u8]> {
         let position = self.position;
 
-        match self.bytes {
-            Some(bytes) => bytes
-                .get(..self.position.into())
+        match self.sink {
+            Some(Sink::Slice(bytes)) => bytes
+                .get(..position.into())
                 .ok_or_else(|| ErrorKind::Truncated.at(position)),
+            #[cfg(feature = "std")]
+            Some(Sink::Writer(_)) => Err(ErrorKind::Failed.at(position)),
             None => Err(ErrorKind::Failed.at(position)),
         }
     }
@@ -105,6 +171,20 @@ impl<'a> Encoder<'a> {
 
         let expected_len = oid.ber_len();
         Header::new(Tag::ObjectIdentifier, expected_len).and_then(|header| header.encode(self))?;
+
+        #[cfg(feature = "std")]
+        if matches!(self.sink, Some(Sink::Writer(_))) {
+            let mut buffer = alloc::vec![0u8; expected_len];
+
+            return if oid.write_ber(&mut buffer)?.len() == expected_len {
+                self.bytes(&buffer)
+            } else {
+                self.error(ErrorKind::Length {
+                    tag: Tag::ObjectIdentifier,
+                })
+            };
+        }
+
         let buffer = self.reserve(expected_len)?;
 
         if oid.write_ber(buffer)?.len() == expected_len {
@@ -121,38 +201,190 @@ impl<'a> Encoder<'a> {
         let expected_len = sequence::encoded_len_inner(encodables)?;
         Header::new(Tag::Sequence, expected_len).and_then(|header| header.encode(self))?;
 
-        let mut nested_encoder = Encoder::new(self.reserve(expected_len)?);
+        let start = self.position;
+
+        for encodable in encodables {
+            encodable.encode(self)?;
+        }
+
+        if (start + expected_len)? == self.position {
+            Ok(())
+        } else {
+            self.error(ErrorKind::Length { tag: Tag::Sequence })
+        }
+    }
+
+    /// Encode a `SET OF` the given values which impl the [`Encodable`]
+    /// trait, sorting the elements into the ascending order DER requires.
+    pub fn set(&mut self, encodables: &[&dyn Encodable]) -> Result<()> {
+        let expected_len = sequence::encoded_len_inner(encodables)?;
+        Header::new(Tag::Set, expected_len).and_then(|header| header.encode(self))?;
+
+        // sorting requires random access to the encoded elements, so
+        // streaming encoders buffer the set body before writing it
+        #[cfg(feature = "std")]
+        if matches!(self.sink, Some(Sink::Writer(_))) {
+            let mut buffer = alloc::vec![0u8; expected_len.to_usize()];
+            let mut nested_encoder = Encoder::new(&mut buffer);
+
+            for encodable in encodables {
+                encodable.encode(&mut nested_encoder)?;
+            }
+
+            return if nested_encoder.finish()?.len() == expected_len.into() {
+                set::sort_encoded(&mut buffer)?;
+                self.bytes(&buffer)
+            } else {
+                Err(ErrorKind::Length { tag: Tag::Set }.into())
+            };
+        }
+
+        let buffer = self.reserve(expected_len)?;
+        let mut nested_encoder = Encoder::new(&mut buffer[..]);
 
         for encodable in encodables {
             encodable.encode(&mut nested_encoder)?;
         }
 
         if nested_encoder.finish()?.len() == expected_len.into() {
-            Ok(())
+            set::sort_encoded(buffer)
         } else {
-            self.error(ErrorKind::Length {
-                tag: Tag::ObjectIdentifier,
-            })
+            Err(ErrorKind::Length { tag: Tag::Set }.into())
+        }
+    }
+
+    /// Encode an ASN.1 `SEQUENCE` whose body is written by the provided
+    /// [`FnOnce`], which receives a nested [`Encoder`].
+    ///
+    /// This handles the header/length bookkeeping for ad-hoc sequences
+    /// without requiring the fields as `&[&dyn Encodable]` trait objects,
+    /// making heterogeneous bodies straightforward in `no_std` contexts.
+    pub fn sequence_nested<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        self.nested_value(Tag::Sequence, f)
+    }
+
+    /// Encode an `EXPLICIT` context-specific field with the provided tag
+    /// number whose contents are written by the provided [`FnOnce`], which
+    /// receives a nested [`Encoder`].
+    pub fn explicit<F>(&mut self, tag_number: u16, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        match Tag::context_specific(tag_number) {
+            Some(tag) => self.nested_value(tag, f),
+            None => self.error(ErrorKind::Overflow),
+        }
+    }
+
+    /// Encode a constructed value with the given [`Tag`] whose body is
+    /// written by the provided [`FnOnce`].
+    ///
+    /// The body is first encoded after a worst-case header gap, then moved
+    /// up against the actual header once its length is known. Only
+    /// supported for slice-backed encoders.
+    fn nested_value<F>(&mut self, tag: Tag, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        let bytes = match self.sink.take() {
+            Some(Sink::Slice(bytes)) => bytes,
+            _ => return Err(ErrorKind::Failed.at(self.position)),
+        };
+
+        // worst-case header: identifier octets + 5 length octets
+        let max_header_len = (tag.encoded_len()? + 5u8)?.to_usize();
+        let position = self.position.to_usize();
+        let body_start = position + max_header_len;
+
+        if body_start > bytes.len() {
+            return Err(ErrorKind::Overlength.at(self.position));
         }
+
+        let mut nested = Encoder::new(&mut bytes[body_start..]);
+        f(&mut nested)?;
+        let body_len = nested.finish()?.len();
+
+        let header = Header::new(tag, body_len)?;
+        let header_len = header.encoded_len()?.to_usize();
+
+        {
+            let mut header_encoder = Encoder::new(&mut bytes[position..position + header_len]);
+            header.encode(&mut header_encoder)?;
+            header_encoder.finish()?;
+        }
+
+        bytes.copy_within(body_start..body_start + body_len, position + header_len);
+
+        self.position = (self.position + (header_len + body_len))?;
+        self.sink = Some(Sink::Slice(bytes));
+        Ok(())
+    }
+
+    /// Get the current position (i.e. number of bytes written) of this
+    /// encoder, e.g. to track the offsets of values within a composite
+    /// output.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Get the number of bytes of space remaining in the output buffer,
+    /// allowing an undersized buffer to be detected before encoding fails
+    /// partway through.
+    ///
+    /// Returns an error for streaming encoders, whose output is unbounded,
+    /// or if the encoder is in a failed state.
+    pub fn remaining_len(&self) -> Result<Length> {
+        self.buffer_len()?
+            .to_usize()
+            .checked_sub(self.position.into())
+            .ok_or_else(|| ErrorKind::Truncated.at(self.position))
+            .and_then(TryInto::try_into)
     }
 
     /// Encode a single byte into the backing buffer.
     pub(crate) fn byte(&mut self, byte: u8) -> Result<()> {
-        match self.reserve(1u8)?.first_mut() {
-            Some(b) => {
-                *b = byte;
-                Ok(())
-            }
-            None => self.error(ErrorKind::Truncated),
-        }
+        self.bytes(&[byte])
     }
 
     /// Encode the provided byte slice into the backing buffer.
     pub(crate) fn bytes(&mut self, slice: &[u8]) -> Result<()> {
+        #[cfg(feature = "std")]
+        if matches!(self.sink, Some(Sink::Writer(_))) {
+            return self.write_to_writer(slice);
+        }
+
         self.reserve(slice.len())?.copy_from_slice(slice);
         Ok(())
     }
 
+    /// Write the provided byte slice to the underlying [`std::io::Write`],
+    /// updating the internal cursor position.
+    #[cfg(feature = "std")]
+    fn write_to_writer(&mut self, slice: &[u8]) -> Result<()> {
+        let len: Length = slice
+            .len()
+            .try_into()
+            .or_else(|_| self.error(ErrorKind::Overflow))?;
+
+        let result = match &mut self.sink {
+            Some(Sink::Writer(writer)) => writer
+                .write_all(slice)
+                .map_err(|e| ErrorKind::Io { kind: e.kind() }),
+            _ => Err(ErrorKind::Failed),
+        };
+
+        match result {
+            Ok(()) => {
+                self.position = (self.position + len).or_else(|e| self.error(e.kind()))?;
+                Ok(())
+            }
+            Err(kind) => self.error(kind),
+        }
+    }
+
     /// Reserve a portion of the internal buffer, updating the internal cursor
     /// position and returning a mutable slice.
     fn reserve(&mut self, len: impl TryInto<Length>) -> Result<&mut [u8]> {
@@ -176,28 +408,148 @@ impl<'a> Encoder<'a> {
         // Unfortunately tainting the buffer on error is tricky to do when
         // potentially holding a reference to the buffer, and failure to taint
         // it would not uphold the invariant that any errors should taint it.
-        let slice = &mut self.bytes.as_mut().expect("DER encoder tainted")[range];
+        #[allow(clippy::expect_used)]
+        let slice = match self.sink.as_mut().expect("DER encoder tainted") {
+            Sink::Slice(bytes) => &mut bytes[range],
+            #[cfg(feature = "std")]
+            Sink::Writer(_) => unreachable!("streaming DER encoders cannot reserve"),
+        };
         *position = end;
 
         Ok(slice)
     }
 
     /// Get the size of the buffer in bytes.
+    ///
+    /// Returns an error for streaming encoders, whose output is unbounded.
     fn buffer_len(&self) -> Result<Length> {
-        self.bytes
-            .as_ref()
-            .map(|bytes| bytes.len())
-            .ok_or_else(|| ErrorKind::Failed.at(self.position))
-            .and_then(TryInto::try_into)
+        match &self.sink {
+            Some(Sink::Slice(bytes)) => bytes.len().try_into(),
+            #[cfg(feature = "std")]
+            Some(Sink::Writer(_)) => Err(ErrorKind::Failed.at(self.position)),
+            None => Err(ErrorKind::Failed.at(self.position)),
+        }
     }
 
-    /// Get the number of bytes still remaining in the buffer.
-    fn remaining_len(&self) -> Result<Length> {
-        self.buffer_len()?
+}
+
+/// DER encoder which writes back-to-front.
+///
+/// The standard [`Encoder`] computes [`Encodable::encoded_len`] for every
+/// nested value before writing it, effectively traversing the structure
+/// once per enclosing level. Writing values from the end of the buffer
+/// instead allows each header to be prepended once the length of its value
+/// is known, encoding deeply nested messages in a single pass.
+///
+/// Note that because values are prepended, the fields of a constructed
+/// value must be encoded in *reverse* order.
+#[derive(Debug)]
+pub struct ReverseEncoder<'a> {
+    /// Buffer into which the DER-encoded message is written, back to front.
+    ///
+    /// In the event an error was previously encountered this will be set to
+    /// `None` to prevent further encoding while in a bad state.
+    bytes: Option<&'a mut [u8]>,
+
+    /// Total number of bytes written to the end of the buffer so far
+    written: Length,
+}
+
+impl<'a> ReverseEncoder<'a> {
+    /// Create a new reverse encoder with the given byte slice as a backing
+    /// buffer.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes: Some(bytes),
+            written: Length::zero(),
+        }
+    }
+
+    /// Encode a value which impls the [`Encodable`] trait, prepending it
+    /// to the data written so far.
+    pub fn encode<T: Encodable>(&mut self, value: &T) -> Result<()> {
+        let length = value.encoded_len()?;
+        let mut encoder = Encoder::new(self.prepend(length)?);
+        value.encode(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Encode a constructed value with the given [`Tag`]: the provided
+    /// [`FnOnce`] writes the value's fields (in reverse order), then the
+    /// header is prepended using the length they actually occupied.
+    pub fn nested<F>(&mut self, tag: Tag, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Self) -> Result<()>,
+    {
+        let start = self.written;
+        f(self)?;
+
+        let body_len = self
+            .written
             .to_usize()
-            .checked_sub(self.position.into())
-            .ok_or_else(|| ErrorKind::Truncated.at(self.position))
-            .and_then(TryInto::try_into)
+            .checked_sub(start.to_usize())
+            .ok_or(ErrorKind::Overflow)?;
+
+        // identifier octets are at most 3 bytes long; length octets at most 5
+        let mut buffer = [0u8; 8];
+        let header = Header::new(tag, body_len)?.encode_to_slice(&mut buffer)?;
+        let header_len = header.len().try_into()?;
+        self.prepend(header_len)?.copy_from_slice(header);
+        Ok(())
+    }
+
+    /// Number of bytes written to the buffer so far.
+    pub fn len(&self) -> Length {
+        self.written
+    }
+
+    /// Has nothing been written to the buffer yet?
+    pub fn is_empty(&self) -> bool {
+        self.written == Length::zero()
+    }
+
+    /// Finish encoding, returning a slice containing the data written to
+    /// the end of the buffer.
+    pub fn finish(self) -> Result<&'a [u8]> {
+        let written = self.written;
+
+        match self.bytes {
+            Some(bytes) => {
+                let start = bytes
+                    .len()
+                    .checked_sub(written.to_usize())
+                    .ok_or_else(|| ErrorKind::Truncated.at(written))?;
+
+                Ok(&bytes[start..])
+            }
+            None => Err(ErrorKind::Failed.at(written)),
+        }
+    }
+
+    /// Reserve a slot of the given length immediately before the data
+    /// written so far, updating the internal cursor.
+    fn prepend(&mut self, length: Length) -> Result<&mut [u8]> {
+        let buffer_len = match &self.bytes {
+            Some(bytes) => bytes.len(),
+            None => return Err(ErrorKind::Failed.at(self.written)),
+        };
+
+        let end = buffer_len
+            .checked_sub(self.written.to_usize())
+            .ok_or(ErrorKind::Truncated)?;
+
+        let start = match end.checked_sub(length.to_usize()) {
+            Some(start) => start,
+            None => {
+                self.bytes.take();
+                return Err(ErrorKind::Overlength.at(self.written));
+            }
+        };
+
+        self.written = (self.written + length)?;
+        #[allow(clippy::expect_used)]
+        Ok(&mut self.bytes.as_mut().expect("DER encoder tainted")[start..end])
     }
 }
 
@@ -206,6 +558,133 @@ mod tests {
     use super::Encoder;
     use crate::{Encodable, ErrorKind, Length};
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_to_vec_appends() {
+        let mut buf = alloc::vec::Vec::new();
+        42i8.encode_to_vec(&mut buf).unwrap();
+        true.encode_to_vec(&mut buf).unwrap();
+        assert_eq!(buf, &[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+
+        assert_eq!(false.to_vec().unwrap(), &[0x01, 0x01, 0x00]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn encode_to_writer_streams() {
+        let mut output = std::vec::Vec::new();
+
+        {
+            let mut encoder = Encoder::new_writer(&mut output);
+            encoder.sequence(&[&42i8, &true]).unwrap();
+        }
+
+        assert_eq!(output, &[0x30, 0x06, 0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+
+        let mut output = std::vec::Vec::new();
+        assert_eq!(
+            false.encode_to_writer(&mut output).unwrap(),
+            Length::from(3u8)
+        );
+        assert_eq!(output, &[0x01, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn nested_closure_encoding() {
+        let mut buffer = [0u8; 24];
+        let mut encoder = Encoder::new(&mut buffer);
+        encoder
+            .sequence_nested(|nested| {
+                nested.encode(&42i8)?;
+                nested.sequence_nested(|inner| inner.encode(&true))?;
+                nested.explicit(0, |field| field.encode(&2i8))
+            })
+            .unwrap();
+        assert_eq!(
+            encoder.finish().unwrap(),
+            &[
+                0x30, 0x0D, 0x02, 0x01, 0x2A, 0x30, 0x03, 0x01, 0x01, 0xFF, 0xA0, 0x03, 0x02,
+                0x01, 0x02
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn nested_closure_encoding_large_body() {
+        use crate::{Decodable, OctetString, Tag};
+        use alloc::vec;
+
+        // a body longer than 64 KiB requires 3 length octets, which the
+        // worst-case header gap must accommodate
+        let payload = vec![0xAB; 0x1_0000];
+        let mut buffer = vec![0u8; payload.len() + 16];
+
+        let mut encoder = Encoder::new(&mut buffer);
+        encoder
+            .sequence_nested(|nested| nested.encode(&OctetString::new(&payload)?))
+            .unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        let mut decoder = crate::Decoder::new(encoded);
+        let any = crate::Any::decode(&mut decoder).unwrap();
+        assert_eq!(any.tag(), Tag::Sequence);
+        let inner = any
+            .sequence(|seq| seq.decode::<OctetString<'_>>())
+            .unwrap();
+        assert_eq!(inner.as_bytes(), payload.as_slice());
+
+        // reverse encoding produces the identical message
+        let encoded = encoded.to_vec();
+        let mut reverse_buffer = vec![0u8; payload.len() + 16];
+        let mut reverse = super::ReverseEncoder::new(&mut reverse_buffer);
+        reverse
+            .nested(Tag::Sequence, |seq| {
+                seq.encode(&OctetString::new(&payload)?)
+            })
+            .unwrap();
+        assert_eq!(reverse.finish().unwrap(), encoded);
+    }
+
+    #[test]
+    fn reverse_encoding() {
+        use super::ReverseEncoder;
+        use crate::Tag;
+
+        // SEQUENCE { INTEGER 42, SEQUENCE { BOOLEAN TRUE } }, with fields
+        // prepended in reverse order
+        let mut buffer = [0u8; 16];
+        let mut encoder = ReverseEncoder::new(&mut buffer);
+        encoder
+            .nested(Tag::Sequence, |outer| {
+                outer.nested(Tag::Sequence, |inner| inner.encode(&true))?;
+                outer.encode(&42i8)
+            })
+            .unwrap();
+        assert_eq!(
+            encoder.finish().unwrap(),
+            &[0x30, 0x08, 0x02, 0x01, 0x2A, 0x30, 0x03, 0x01, 0x01, 0xFF]
+        );
+
+        // an undersized buffer is an error
+        let mut buffer = [0u8; 2];
+        let mut encoder = ReverseEncoder::new(&mut buffer);
+        let err = encoder.encode(&42i8).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+
+    #[test]
+    fn position_introspection() {
+        let mut buffer = [0u8; 8];
+        let mut encoder = Encoder::new(&mut buffer);
+        assert_eq!(encoder.position(), Length::zero());
+        assert_eq!(encoder.remaining_len().unwrap(), Length::from(8u8));
+
+        encoder.encode(&42i8).unwrap();
+        assert_eq!(encoder.position(), Length::from(3u8));
+        assert_eq!(encoder.remaining_len().unwrap(), Length::from(5u8));
+    }
+
     #[test]
     fn overlength_message() {
         let mut buffer = [];
@@ -214,4 +693,13 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::Overlength);
         assert_eq!(err.position(), Some(Length::zero()));
     }
+
+    #[test]
+    fn default_fields_are_omitted() {
+        let mut buffer = [0u8; 8];
+        let mut encoder = Encoder::new(&mut buffer);
+        encoder.encode_with_default(&true, &true).unwrap();
+        encoder.encode_with_default(&false, &true).unwrap();
+        assert_eq!(encoder.finish().unwrap(), &[0x01, 0x01, 0x00]);
+    }
 }
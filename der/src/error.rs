@@ -75,6 +75,12 @@ impl From<core::convert::Infallible> for Error {
     }
 }
 
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Error {
+        ErrorKind::Failed.into()
+    }
+}
+
 #[cfg(feature = "oid")]
 impl From<const_oid::Error> for Error {
     fn from(_: const_oid::Error) -> Error {
@@ -82,6 +88,13 @@ impl From<const_oid::Error> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ErrorKind {}
 
@@ -89,15 +102,37 @@ impl std::error::Error for ErrorKind {}
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// Character outside of the allowed set for a given string type
+    CharSet {
+        /// Tag of the string type being decoded
+        tag: Tag,
+    },
+
+    /// Date-and-time related errors
+    DateTime,
+
     /// Operation failed due to previous error
     Failed,
 
+    /// I/O errors
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Io {
+        /// [`std::io::ErrorKind`] of the error which occurred
+        kind: std::io::ErrorKind,
+    },
+
     /// Incorrect length for a given field
     Length {
         /// Tag type of the value being decoded
         tag: Tag,
     },
 
+    /// Nested message exceeded the decoder's depth limit.
+    ///
+    /// See [`Decoder::with_depth_limit`][`crate::Decoder::with_depth_limit`].
+    NestedTooDeep,
+
     /// Message is not canonically encoded
     Noncanonical,
 
@@ -110,6 +145,18 @@ pub enum ErrorKind {
     /// Message is longer than this library's internal limits support
     Overlength,
 
+    /// Input exceeds the maximum length a [`ByteSlice`][`crate::ByteSlice`]
+    /// (and therefore a DER value) can represent, i.e. [`Length::max`]
+    Oversized {
+        /// Actual length of the input
+        length: usize,
+    },
+
+    /// PEM encoding errors
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    Pem,
+
     /// Undecoded trailing data at end of message
     TrailingData {
         /// Length of the decoded data
@@ -168,12 +215,24 @@ impl ErrorKind {
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ErrorKind::CharSet { tag } => {
+                write!(f, "character outside of the allowed set for {}", tag)
+            }
+            ErrorKind::DateTime => write!(f, "date/time error"),
             ErrorKind::Failed => write!(f, "operation failed"),
+            #[cfg(feature = "std")]
+            ErrorKind::Io { kind } => write!(f, "I/O error: {:?}", kind),
             ErrorKind::Length { tag } => write!(f, "incorrect length for {}", tag),
+            ErrorKind::NestedTooDeep => write!(f, "DER message nested too deeply"),
             ErrorKind::Noncanonical => write!(f, "DER is not canonically encoded"),
             ErrorKind::Oid => write!(f, "malformed OID"),
             ErrorKind::Overflow => write!(f, "integer overflow"),
             ErrorKind::Overlength => write!(f, "DER message is too long"),
+            ErrorKind::Oversized { length } => {
+                write!(f, "input of {} bytes exceeds the DER length limit", length)
+            }
+            #[cfg(feature = "pem")]
+            ErrorKind::Pem => write!(f, "PEM encoding error"),
             ErrorKind::TrailingData { decoded, remaining } => {
                 write!(
                     f,
@@ -203,3 +262,20 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Error, ErrorKind};
+    use crate::Length;
+    use std::error::Error as _;
+
+    #[test]
+    fn source_chains_to_kind() {
+        let err = Error::new(ErrorKind::Oid, Length::zero());
+        let source = err.source().unwrap();
+        assert_eq!(
+            Some(&ErrorKind::Oid),
+            source.downcast_ref::<ErrorKind>()
+        );
+    }
+}
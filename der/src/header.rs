@@ -4,8 +4,21 @@ use crate::{Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Result, T
 use core::convert::TryInto;
 
 /// ASN.1 DER headers: tag + length component of TLV-encoded values
+///
+/// Reading and writing headers directly is supported public API, so
+/// downstream crates implementing exotic types (e.g. application-tagged
+/// wrappers) don't have to re-derive header parsing from the spec:
+///
+/// ```
+/// use der::{Decodable, Decoder, Header, Length, Tag};
+///
+/// let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A]);
+/// let header = Header::decode(&mut decoder).unwrap();
+/// assert_eq!(header.tag, Tag::Integer);
+/// assert_eq!(header.length, Length::from(1u8));
+/// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) struct Header {
+pub struct Header {
     /// Tag representing the type of the encoded value
     pub tag: Tag,
 
@@ -21,6 +34,34 @@ impl Header {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
         Ok(Self { tag, length })
     }
+
+    /// Compute the encoded length of this header in a `const` context, so
+    /// the size of a fixed, compile-time-known structure can be used as an
+    /// array length:
+    ///
+    /// ```
+    /// use der::{Header, Length, Tag};
+    ///
+    /// /// `AlgorithmIdentifier` header for `rsaEncryption`
+    /// const HEADER: Header = Header {
+    ///     tag: Tag::Sequence,
+    ///     length: Length::new(13),
+    /// };
+    ///
+    /// /// Size of the entire TLV encoding
+    /// const ENCODED_LEN: usize = HEADER
+    ///     .const_encoded_len()
+    ///     .const_add(HEADER.length)
+    ///     .to_usize();
+    ///
+    /// let buffer = [0u8; ENCODED_LEN];
+    /// assert_eq!(buffer.len(), 15);
+    /// ```
+    pub const fn const_encoded_len(&self) -> Length {
+        self.tag
+            .const_encoded_len()
+            .const_add(self.length.const_encoded_len())
+    }
 }
 
 impl Decodable<'_> for Header {
@@ -49,3 +90,46 @@ impl Encodable for Header {
         self.length.encode(encoder)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Header;
+    use crate::{Decodable, Encodable, Length, Tag, TagNumber};
+
+    /// `[APPLICATION 2]` (constructed) with a 256-byte body
+    const APPLICATION_EXAMPLE: &[u8] = &[0x62, 0x82, 0x01, 0x00];
+
+    #[test]
+    fn decode_application_tag() {
+        let header = Header::from_bytes(APPLICATION_EXAMPLE).unwrap();
+        assert_eq!(
+            header.tag,
+            Tag::Application {
+                constructed: true,
+                number: TagNumber::new(2),
+            }
+        );
+        assert_eq!(header.length, Length::from(256u16));
+    }
+
+    #[test]
+    fn const_encoded_len_matches_encodable() {
+        let header = Header::from_bytes(APPLICATION_EXAMPLE).unwrap();
+        assert_eq!(header.const_encoded_len(), header.encoded_len().unwrap());
+
+        // usable as an array length
+        const LEN: usize = Length::new(256).const_encoded_len().to_usize();
+        let buffer = [0u8; LEN];
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let header = Header::from_bytes(APPLICATION_EXAMPLE).unwrap();
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            APPLICATION_EXAMPLE,
+            header.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+}
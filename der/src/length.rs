@@ -7,11 +7,18 @@ use core::{convert::TryFrom, fmt, ops::Add};
 ///
 /// # Limits
 ///
-/// Presently constrained to the range `0..=65535`
+/// Presently constrained to the range `0..=4294967295` (i.e. `u32`),
+/// supporting long-form length prefixes of up to five octets.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
-pub struct Length(u16);
+pub struct Length(u32);
 
 impl Length {
+    /// Create a new [`Length`], e.g. when declaring the size of a
+    /// fixed, compile-time-known structure as a constant.
+    pub const fn new(value: u32) -> Self {
+        Length(value)
+    }
+
     /// Return a length of `0`.
     pub const fn zero() -> Self {
         Length(0)
@@ -19,24 +26,87 @@ impl Length {
 
     /// Get the maximum length supported by this crate
     pub const fn max() -> usize {
-        u16::MAX as usize
+        u32::MAX as usize
     }
 
     /// Convert length to `usize`
-    pub fn to_usize(self) -> usize {
-        self.0.into()
+    pub const fn to_usize(self) -> usize {
+        self.0 as usize
     }
-}
 
-impl Add for Length {
-    type Output = Result<Self>;
+    /// Add two lengths in a `const` context.
+    ///
+    /// Unlike the [`Add`] impl there is no [`Result`] to propagate, so
+    /// overflow panics — a compile error when evaluated at compile time.
+    // `const fn`s cannot return `Result`; see the note above
+    #[allow(clippy::panic)]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        match self.0.checked_add(rhs.0) {
+            Some(sum) => Length(sum),
+            None => panic!("DER length overflow"),
+        }
+    }
 
-    fn add(self, other: Self) -> Result<Self> {
+    /// Compute the encoded length of this length prefix (1-5 octets) in a
+    /// `const` context; the `const`-compatible equivalent of its
+    /// [`Encodable`] impl.
+    pub const fn const_encoded_len(self) -> Length {
+        match self.0 {
+            0..=0x7F => Length(1),
+            0x80..=0xFF => Length(2),
+            0x100..=0xFFFF => Length(3),
+            0x1_0000..=0xFF_FFFF => Length(4),
+            0x100_0000..=0xFFFF_FFFF => Length(5),
+        }
+    }
+
+    /// Perform checked addition, returning [`ErrorKind::Overflow`] on overflow.
+    pub fn checked_add(self, other: Length) -> Result<Self> {
         self.0
             .checked_add(other.0)
             .map(Length)
             .ok_or_else(|| ErrorKind::Overflow.into())
     }
+
+    /// Perform checked multiplication, returning [`ErrorKind::Overflow`] on
+    /// overflow.
+    pub fn checked_mul(self, other: Length) -> Result<Self> {
+        self.0
+            .checked_mul(other.0)
+            .map(Length)
+            .ok_or_else(|| ErrorKind::Overflow.into())
+    }
+
+    /// Perform saturating addition, returning [`Length::max`] on overflow.
+    pub fn saturating_add(self, other: Length) -> Self {
+        Length(self.0.saturating_add(other.0))
+    }
+
+    /// Perform saturating subtraction, returning [`Length::zero`] on underflow.
+    pub fn saturating_sub(self, other: Length) -> Self {
+        Length(self.0.saturating_sub(other.0))
+    }
+
+    /// Get the initial octet of the encoding of this [`Length`] if it
+    /// requires the long (i.e. multi-byte) form, or `None` if it fits in
+    /// the short form.
+    fn initial_octet(self) -> Option<u8> {
+        match self.0 {
+            0x80..=0xFF => Some(0x81),
+            0x100..=0xFFFF => Some(0x82),
+            0x1_0000..=0xFF_FFFF => Some(0x83),
+            0x100_0000..=0xFFFF_FFFF => Some(0x84),
+            _ => None,
+        }
+    }
+}
+
+impl Add for Length {
+    type Output = Result<Self>;
+
+    fn add(self, other: Self) -> Result<Self> {
+        self.checked_add(other)
+    }
 }
 
 impl Add<u8> for Length {
@@ -55,6 +125,14 @@ impl Add<u16> for Length {
     }
 }
 
+impl Add<u32> for Length {
+    type Output = Result<Self>;
+
+    fn add(self, other: u32) -> Result<Self> {
+        self + Length::from(other)
+    }
+}
+
 impl Add<usize> for Length {
     type Output = Result<Self>;
 
@@ -73,18 +151,24 @@ impl Add<Length> for Result<Length> {
 
 impl From<u8> for Length {
     fn from(len: u8) -> Length {
-        Length(len as u16)
+        Length(len as u32)
     }
 }
 
 impl From<u16> for Length {
     fn from(len: u16) -> Length {
+        Length(len as u32)
+    }
+}
+
+impl From<u32> for Length {
+    fn from(len: u32) -> Length {
         Length(len)
     }
 }
 
-impl From<Length> for u16 {
-    fn from(len: Length) -> u16 {
+impl From<Length> for u32 {
+    fn from(len: Length) -> u32 {
         len.0
     }
 }
@@ -99,7 +183,7 @@ impl TryFrom<usize> for Length {
     type Error = Error;
 
     fn try_from(len: usize) -> Result<Length> {
-        u16::try_from(len)
+        u32::try_from(len)
             .map(Length)
             .map_err(|_| ErrorKind::Overflow.into())
     }
@@ -111,31 +195,30 @@ impl Decodable<'_> for Length {
             // Note: per X.690 Section 8.1.3.6.1 the byte 0x80 encodes indefinite
             // lengths, which are not allowed in DER, so disallow that byte.
             len if len < 0x80 => Ok(len.into()),
-            0x81 => {
-                let len = decoder.byte()?;
-
-                // X.690 Section 10.1: DER lengths must be encoded with a minimum
-                // number of octets
-                if len >= 0x80 {
-                    Ok(len.into())
-                } else {
-                    Err(ErrorKind::Noncanonical.into())
+            // 1-4 byte variable-sized length prefix
+            tag @ 0x81..=0x84 => {
+                let nbytes = (tag & 0x7F) as usize;
+                debug_assert!(nbytes <= 4);
+
+                let mut decoded_len = 0u32;
+                for _ in 0..nbytes {
+                    decoded_len = (decoded_len << 8) | decoder.byte()? as u32;
                 }
-            }
-            0x82 => {
-                let len_hi = decoder.byte()? as u16;
-                let len = (len_hi << 8) | (decoder.byte()? as u16);
+
+                let length = Length(decoded_len);
 
                 // X.690 Section 10.1: DER lengths must be encoded with a minimum
                 // number of octets
-                if len > 0xFF {
-                    Ok(len.into())
+                if length.initial_octet() == Some(tag)
+                    || decoder.options().accept_non_minimal_lengths
+                {
+                    Ok(length)
                 } else {
                     Err(ErrorKind::Noncanonical.into())
                 }
             }
             _ => {
-                // We specialize to a maximum 3-byte length
+                // We specialize to a maximum 5-byte length (i.e. 32-bit values)
                 Err(ErrorKind::Overlength.into())
             }
         }
@@ -148,21 +231,29 @@ impl Encodable for Length {
             0..=0x7F => Ok(Length(1)),
             0x80..=0xFF => Ok(Length(2)),
             0x100..=0xFFFF => Ok(Length(3)),
+            0x1_0000..=0xFF_FFFF => Ok(Length(4)),
+            0x100_0000..=0xFFFF_FFFF => Ok(Length(5)),
         }
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        match self.0 {
-            0..=0x7F => encoder.byte(self.0 as u8),
-            0x80..=0xFF => {
-                encoder.byte(0x81)?;
-                encoder.byte(self.0 as u8)
-            }
-            0x100..=0xFFFF => {
-                encoder.byte(0x82)?;
-                encoder.byte((self.0 >> 8) as u8)?;
-                encoder.byte((self.0 & 0xFF) as u8)
+        match self.initial_octet() {
+            Some(tag_byte) => {
+                encoder.byte(tag_byte)?;
+
+                // Strip leading zeroes
+                let mut length_started = false;
+
+                for len_byte in self.0.to_be_bytes().iter().cloned() {
+                    if length_started || len_byte != 0 {
+                        length_started = true;
+                        encoder.byte(len_byte)?;
+                    }
+                }
+
+                Ok(())
             }
+            None => encoder.byte(self.0 as u8),
         }
     }
 }
@@ -198,11 +289,26 @@ mod tests {
             Length::from(0x100u16),
             Length::from_bytes(&[0x82, 0x01, 0x00]).unwrap()
         );
+
+        assert_eq!(
+            Length::from(0x1_0000u32),
+            Length::from_bytes(&[0x83, 0x01, 0x00, 0x00]).unwrap()
+        );
+
+        assert_eq!(
+            Length::from(0x100_0000u32),
+            Length::from_bytes(&[0x84, 0x01, 0x00, 0x00, 0x00]).unwrap()
+        );
+
+        assert_eq!(
+            Length::from(u32::MAX),
+            Length::from_bytes(&[0x84, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap()
+        );
     }
 
     #[test]
     fn encode() {
-        let mut buffer = [0u8; 3];
+        let mut buffer = [0u8; 5];
 
         assert_eq!(
             &[0x00],
@@ -228,10 +334,70 @@ mod tests {
             &[0x82, 0x01, 0x00],
             Length::from(0x100u16).encode_to_slice(&mut buffer).unwrap()
         );
+
+        assert_eq!(
+            &[0x83, 0x01, 0x00, 0x00],
+            Length::from(0x1_0000u32)
+                .encode_to_slice(&mut buffer)
+                .unwrap()
+        );
+
+        assert_eq!(
+            &[0x84, 0xFF, 0xFF, 0xFF, 0xFF],
+            Length::from(u32::MAX).encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(
+            Length::from(3u8),
+            Length::from(1u8).checked_add(Length::from(2u8)).unwrap()
+        );
+
+        assert!(Length::from(u32::MAX)
+            .checked_add(Length::from(1u8))
+            .is_err());
+
+        assert_eq!(
+            Length::from(6u8),
+            Length::from(2u8).checked_mul(Length::from(3u8)).unwrap()
+        );
+
+        assert!(Length::from(u32::MAX)
+            .checked_mul(Length::from(2u8))
+            .is_err());
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(
+            Length::from(u32::MAX),
+            Length::from(u32::MAX).saturating_add(Length::from(1u8))
+        );
+
+        assert_eq!(
+            Length::zero(),
+            Length::from(1u8).saturating_sub(Length::from(2u8))
+        );
+
+        assert_eq!(
+            Length::from(1u8),
+            Length::from(3u8).saturating_sub(Length::from(2u8))
+        );
     }
 
     #[test]
     fn reject_indefinite_lengths() {
         assert!(Length::from_bytes(&[0x80]).is_err());
     }
+
+    #[test]
+    fn reject_non_minimal_lengths() {
+        assert!(Length::from_bytes(&[0x81, 0x00]).is_err());
+        assert!(Length::from_bytes(&[0x81, 0x7F]).is_err());
+        assert!(Length::from_bytes(&[0x82, 0x00, 0xFF]).is_err());
+        assert!(Length::from_bytes(&[0x83, 0x00, 0xFF, 0xFF]).is_err());
+        assert!(Length::from_bytes(&[0x84, 0x00, 0xFF, 0xFF, 0xFF]).is_err());
+    }
 }
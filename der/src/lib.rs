@@ -39,11 +39,32 @@
 //!
 //! - [`Any`] (ASN.1 `ANY`)
 //! - [`BitString`] (ASN.1 `BIT STRING`)
+//! - [`BmpString`] (ASN.1 `BMPString`)
+//! - [`ContextSpecific`] (`EXPLICIT` context-specific fields)
+//! - [`Enumerated`] (ASN.1 `ENUMERATED`)
+//! - [`GeneralString`] (ASN.1 `GeneralString`)
+//! - [`GeneralizedTime`] (ASN.1 `GeneralizedTime`)
+//! - [`Ia5String`] (ASN.1 `IA5String`)
 //! - [`Null`] (ASN.1 `NULL`)
+//! - [`NumericString`] (ASN.1 `NumericString`)
 //! - [`ObjectIdentifier`] (ASN.1 `OBJECT IDENTIFIER`)
 //! - [`OctetString`] (ASN.1 `OCTET STRING`)
+//! - [`PrintableString`] (ASN.1 `PrintableString`)
 //! - [`RawInteger`] (ASN.1 `INTEGER` with raw access to encoded bytes)
+//! - [`Real`] (ASN.1 `REAL`)
+//! - [`RelativeOid`] (ASN.1 `RELATIVE-OID`)
 //! - [`Sequence`] (ASN.1 `SEQUENCE`)
+//! - [`SequenceOf`] (ASN.1 `SEQUENCE OF` with a fixed capacity)
+//! - [`Set`] (ASN.1 `SET` and `SET OF`)
+//! - [`SetOf`] (ASN.1 `SET OF` with a fixed capacity)
+//! - [`TeletexString`] (ASN.1 `TeletexString`)
+//! - [`UIntBytes`] (ASN.1 unsigned `INTEGER` of arbitrary precision)
+//! - [`UniversalString`] (ASN.1 `UniversalString`)
+//! - [`UtcTime`] (ASN.1 `UTCTime`)
+//! - [`Utf8String`] (ASN.1 `UTF8String`)
+//! - [`VideotexString`] (ASN.1 `VideotexString`)
+//! - [`VisibleString`] (ASN.1 `VisibleString`)
+//! - [`str`][`prim@str`] (ASN.1 `UTF8String`, encode-only)
 //!
 //! ## Example
 //!
@@ -212,9 +233,9 @@
 //!
 //! ## Custom derive support
 //!
-//! When the `derive` feature of this crate is enabled, a custom derive macro
-//! is available for the [`Message`] trait. See [`der_derive::Message`] for
-//! more information.
+//! When the `derive` feature of this crate is enabled, custom derive macros
+//! are available for the [`Message`] and [`Choice`] traits. See
+//! [`der_derive::Message`] and [`der_derive::Choice`] for more information.
 //!
 //! It can be used to automatically derive the code given in the above example:
 //!
@@ -295,6 +316,16 @@
 //! # }
 //! ```
 //!
+//! ## Untrusted input and panics
+//!
+//! Decoding is intended to be panic-free on arbitrary untrusted input:
+//! malformed or truncated messages are reported via [`Error`] rather than
+//! panicking. The crate forbids `unsafe` code, denies `panic!`/`unwrap`/
+//! `expect` in library code, and the `cargo fuzz` targets in `der/fuzz`
+//! exercise the [`Any`], `SEQUENCE`, `INTEGER`, and OID decoders with
+//! arbitrary bytes. Any panic reachable from untrusted input is
+//! considered a bug.
+//!
 //! [X.690]: https://www.itu.int/rec/T-REC-X.690/
 //! [RustCrypto]: https://github.com/rustcrypto
 //! [`pkcs8`]: https://docs.rs/pkcs8/
@@ -308,6 +339,9 @@
     html_root_url = "https://docs.rs/der/0.1.0"
 )]
 #![forbid(unsafe_code)]
+// decoding must never panic on untrusted input; see "Untrusted input and
+// panics" above
+#![cfg_attr(not(test), deny(clippy::panic, clippy::unwrap_used, clippy::expect_used))]
 #![warn(missing_docs, rust_2018_idioms)]
 
 #[cfg(feature = "alloc")]
@@ -318,36 +352,92 @@ extern crate std;
 
 mod asn1;
 mod byte_slice;
+mod datetime;
 mod decoder;
+#[cfg(feature = "alloc")]
+mod document;
+#[cfg(feature = "dump")]
+mod dump;
 mod encoder;
 mod error;
 mod header;
 mod length;
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub mod oid;
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub mod pem;
+#[cfg(feature = "std")]
+mod reader;
 mod tag;
 mod traits;
+#[cfg(feature = "alloc")]
+mod validate;
 
 pub use crate::{
     asn1::{
         any::Any,
-        bit_string::BitString,
-        integer::RawInteger,
+        bit_string::{BitString, Bits, NamedBits, SetBits},
+        bmp_string::{BmpChars, BmpString},
+        context_specific::ContextSpecific,
+        enumerated::Enumerated,
+        general_string::GeneralString,
+        generalized_time::GeneralizedTime,
+        ia5_string::Ia5String,
+        integer::{RawInteger, UIntBytes},
         null::Null,
+        numeric_string::NumericString,
         octet_string::OctetString,
-        sequence::{self, Sequence},
+        printable_string::PrintableString,
+        real::Real,
+        relative_oid::RelativeOid,
+        sequence::{self, Sequence, SequenceIter},
+        sequence_of::{SequenceOf, SequenceOfIter},
+        set::Set,
+        set_of::{SetOf, SetOfIter},
+        teletex_string::TeletexString,
+        universal_string::UniversalString,
+        utc_time::UtcTime,
+        utf8_string::Utf8String,
+        videotex_string::VideotexString,
+        visible_string::VisibleString,
     },
-    decoder::Decoder,
-    encoder::Encoder,
+    datetime::DateTime,
+    decoder::{DecodeOptions, Decoder, EncodingRules},
+    encoder::{Encoder, ReverseEncoder},
     error::{Error, ErrorKind, Result},
+    header::Header,
     length::Length,
-    tag::Tag,
-    traits::{Decodable, Encodable, Message, Tagged},
+    tag::{Class, Tag, TagMode, TagNumber},
+    traits::{Choice, Decodable, DynTagged, Encodable, Message, Tagged},
 };
 
-pub(crate) use crate::{byte_slice::ByteSlice, header::Header};
+pub use crate::byte_slice::ByteSlice;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::{
+    asn1::any::AnyOwned,
+    document::Document,
+    validate::{validate, Violation, ViolationKind},
+};
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use crate::document::SecretDocument;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::reader::Reader;
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use der_derive::Message;
+pub use der_derive::{Choice, Enumerated, Message};
+
+#[cfg(feature = "dump")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dump")))]
+pub use crate::dump::dump;
 
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
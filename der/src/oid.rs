@@ -0,0 +1,37 @@
+//! Object identifier support: database of well-known OIDs.
+
+pub mod db;
+
+/// Construct a compile-time checked [`ObjectIdentifier`] from its
+/// dotted-decimal string representation.
+///
+/// ```
+/// use der::{oid, ObjectIdentifier};
+///
+/// const SHA_256_WITH_RSA: ObjectIdentifier = oid!("1.2.840.113549.1.1.11");
+/// ```
+///
+/// Malformed strings are rejected with a compile error.
+///
+/// [`ObjectIdentifier`]: crate::ObjectIdentifier
+#[macro_export]
+macro_rules! oid {
+    ($string:expr) => {{
+        const OID: $crate::ObjectIdentifier = $crate::ObjectIdentifier::parse($string);
+        OID
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ObjectIdentifier;
+
+    #[test]
+    fn oid_macro() {
+        const OID: ObjectIdentifier = oid!("1.2.840.113549.1.1.11");
+        assert_eq!(OID, ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]));
+
+        // also usable in non-const position
+        assert_eq!(OID, oid!("1.2.840.113549.1.1.11"));
+    }
+}
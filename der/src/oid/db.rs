@@ -0,0 +1,146 @@
+//! Database of well-known [`ObjectIdentifier`] constants.
+//!
+//! Covers common public key algorithms, signature algorithms, digest
+//! algorithms, and X.500 attribute types, so consumers don't each need to
+//! maintain their own partially overlapping lists.
+
+use crate::ObjectIdentifier;
+
+/// `rsaEncryption` (RFC 8017)
+pub const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 1]);
+
+/// `sha256WithRSAEncryption` (RFC 8017)
+pub const SHA_256_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]);
+
+/// `sha384WithRSAEncryption` (RFC 8017)
+pub const SHA_384_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 12]);
+
+/// `sha512WithRSAEncryption` (RFC 8017)
+pub const SHA_512_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 13]);
+
+/// `id-ecPublicKey` (RFC 5480)
+pub const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 2, 1]);
+
+/// `secp256r1` a.k.a. NIST P-256 (RFC 5480)
+pub const SECP_256_R_1: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 3, 1, 7]);
+
+/// `secp384r1` a.k.a. NIST P-384 (RFC 5480)
+pub const SECP_384_R_1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 132, 0, 34]);
+
+/// `secp521r1` a.k.a. NIST P-521 (RFC 5480)
+pub const SECP_521_R_1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 132, 0, 35]);
+
+/// `secp256k1` (SEC 2)
+pub const SECP_256_K_1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 132, 0, 10]);
+
+/// `ecdsa-with-SHA256` (RFC 5758)
+pub const ECDSA_WITH_SHA_256: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 4, 3, 2]);
+
+/// `ecdsa-with-SHA384` (RFC 5758)
+pub const ECDSA_WITH_SHA_384: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 4, 3, 3]);
+
+/// `ecdsa-with-SHA512` (RFC 5758)
+pub const ECDSA_WITH_SHA_512: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 4, 3, 4]);
+
+/// `id-X25519` (RFC 8410)
+pub const X_25519: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 110]);
+
+/// `id-Ed25519` (RFC 8410)
+pub const ED_25519: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 112]);
+
+/// `id-X448` (RFC 8410)
+pub const X_448: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 111]);
+
+/// `id-Ed448` (RFC 8410)
+pub const ED_448: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 113]);
+
+/// `id-sha1` (RFC 3174)
+pub const SHA_1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 14, 3, 2, 26]);
+
+/// `id-sha224` (RFC 5754)
+pub const SHA_224: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 4]);
+
+/// `id-sha256` (RFC 5754)
+pub const SHA_256: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 1]);
+
+/// `id-sha384` (RFC 5754)
+pub const SHA_384: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 2]);
+
+/// `id-sha512` (RFC 5754)
+pub const SHA_512: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 3]);
+
+/// `id-at-commonName` (X.520)
+pub const COMMON_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 3]);
+
+/// `id-at-countryName` (X.520)
+pub const COUNTRY_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 6]);
+
+/// `id-at-localityName` (X.520)
+pub const LOCALITY_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 7]);
+
+/// `id-at-stateOrProvinceName` (X.520)
+pub const STATE_OR_PROVINCE_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 8]);
+
+/// `id-at-organizationName` (X.520)
+pub const ORGANIZATION_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 10]);
+
+/// `id-at-organizationalUnitName` (X.520)
+pub const ORGANIZATIONAL_UNIT_NAME: ObjectIdentifier = ObjectIdentifier::new(&[2, 5, 4, 11]);
+
+/// Lookup table mapping each well-known OID to its human-readable name.
+const DB: &[(ObjectIdentifier, &str)] = &[
+    (RSA_ENCRYPTION, "rsaEncryption"),
+    (SHA_256_WITH_RSA_ENCRYPTION, "sha256WithRSAEncryption"),
+    (SHA_384_WITH_RSA_ENCRYPTION, "sha384WithRSAEncryption"),
+    (SHA_512_WITH_RSA_ENCRYPTION, "sha512WithRSAEncryption"),
+    (EC_PUBLIC_KEY, "id-ecPublicKey"),
+    (SECP_256_R_1, "secp256r1"),
+    (SECP_384_R_1, "secp384r1"),
+    (SECP_521_R_1, "secp521r1"),
+    (SECP_256_K_1, "secp256k1"),
+    (ECDSA_WITH_SHA_256, "ecdsa-with-SHA256"),
+    (ECDSA_WITH_SHA_384, "ecdsa-with-SHA384"),
+    (ECDSA_WITH_SHA_512, "ecdsa-with-SHA512"),
+    (X_25519, "id-X25519"),
+    (ED_25519, "id-Ed25519"),
+    (X_448, "id-X448"),
+    (ED_448, "id-Ed448"),
+    (SHA_1, "id-sha1"),
+    (SHA_224, "id-sha224"),
+    (SHA_256, "id-sha256"),
+    (SHA_384, "id-sha384"),
+    (SHA_512, "id-sha512"),
+    (COMMON_NAME, "id-at-commonName"),
+    (COUNTRY_NAME, "id-at-countryName"),
+    (LOCALITY_NAME, "id-at-localityName"),
+    (STATE_OR_PROVINCE_NAME, "id-at-stateOrProvinceName"),
+    (ORGANIZATION_NAME, "id-at-organizationName"),
+    (ORGANIZATIONAL_UNIT_NAME, "id-at-organizationalUnitName"),
+];
+
+/// Look up the human-readable name of a well-known OID, e.g. for debugging
+/// output, returning `None` if the OID is not in the database.
+pub fn name(oid: &ObjectIdentifier) -> Option<&'static str> {
+    DB.iter()
+        .find(|(known, _)| known == oid)
+        .map(|(_, name)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{name, ObjectIdentifier, COMMON_NAME, RSA_ENCRYPTION};
+
+    #[test]
+    fn lookup_known_oids() {
+        assert_eq!(Some("rsaEncryption"), name(&RSA_ENCRYPTION));
+        assert_eq!(Some("id-at-commonName"), name(&COMMON_NAME));
+    }
+
+    #[test]
+    fn lookup_unknown_oid() {
+        assert_eq!(None, name(&ObjectIdentifier::new(&[1, 2, 3, 4])));
+    }
+}
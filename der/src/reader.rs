@@ -0,0 +1,146 @@
+//! Streaming DER reader for [`std::io::Read`] sources.
+
+use crate::{asn1::any::AnyOwned, Any, Decodable, Decoder, ErrorKind, Header, Result};
+use alloc::vec::Vec;
+use std::io::{self, Read};
+
+/// Maximum size of a DER header this crate can produce: a 3-byte tag
+/// followed by a 5-byte length prefix.
+const MAX_HEADER_LEN: usize = 8;
+
+/// Streaming DER reader which pulls top-level TLVs from any
+/// [`std::io::Read`] source, e.g. a file or socket.
+///
+/// Only one TLV is buffered at a time, so inputs much larger than
+/// available memory (e.g. CMS signed data containing large payloads) can
+/// be processed incrementally — as long as each individual top-level
+/// value fits in memory.
+///
+/// Values are yielded as [`AnyOwned`] so they outlive the internal
+/// buffer; use [`AnyOwned::to_any`] to decode them as concrete types.
+pub struct Reader<R: io::Read> {
+    /// Source being read from
+    inner: R,
+
+    /// Buffered bytes of the TLV currently being assembled
+    buffer: Vec<u8>,
+}
+
+impl<R: io::Read> Reader<R> {
+    /// Create a new [`Reader`] wrapping the given source.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Read the next top-level TLV, or `None` if the source is cleanly
+    /// exhausted (i.e. end-of-input falls on a TLV boundary).
+    ///
+    /// The header is parsed as soon as it is available and only the
+    /// announced number of content octets is then read, so trailing data
+    /// after the TLV is left unconsumed in the source.
+    pub fn read_tlv(&mut self) -> Result<Option<AnyOwned>> {
+        self.fill(MAX_HEADER_LEN)?;
+
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let mut decoder = Decoder::new(&self.buffer);
+        let header = Header::decode(&mut decoder)?;
+        let total_len = (header.length + decoder.position())?.to_usize();
+
+        self.fill(total_len)?;
+
+        if self.buffer.len() < total_len {
+            return Err(ErrorKind::Truncated.into());
+        }
+
+        let any = AnyOwned::from(Any::from_bytes(&self.buffer[..total_len])?);
+        self.buffer.drain(..total_len);
+        Ok(Some(any))
+    }
+
+    /// Unwrap this [`Reader`], returning the underlying source.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read from the source until the buffer holds at least `target`
+    /// bytes or the source is exhausted.
+    fn fill(&mut self, target: usize) -> Result<()> {
+        while self.buffer.len() < target {
+            let wanted = (target - self.buffer.len()) as u64;
+
+            let read = io::Read::take(&mut self.inner, wanted)
+                .read_to_end(&mut self.buffer)
+                .map_err(|e| ErrorKind::Io { kind: e.kind() })?;
+
+            if read == 0 {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: io::Read> Iterator for Reader<R> {
+    type Item = Result<AnyOwned>;
+
+    fn next(&mut self) -> Option<Result<AnyOwned>> {
+        self.read_tlv().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reader;
+    use crate::Tag;
+    use alloc::{vec, vec::Vec};
+    use core::convert::TryFrom;
+    use std::io::Cursor;
+
+    #[test]
+    fn reads_consecutive_tlvs() {
+        // INTEGER 42, then an OCTET STRING longer than the header prefetch
+        let mut input = vec![0x02, 0x01, 0x2A, 0x04, 0x20];
+        input.extend_from_slice(&[0xAB; 32]);
+
+        let mut reader = Reader::new(Cursor::new(input));
+
+        let first = reader.read_tlv().unwrap().unwrap();
+        assert_eq!(first.tag(), Tag::Integer);
+        assert_eq!(i8::try_from(first.to_any().unwrap()).unwrap(), 42);
+
+        let second = reader.read_tlv().unwrap().unwrap();
+        assert_eq!(second.tag(), Tag::OctetString);
+        assert_eq!(second.as_bytes(), &[0xAB; 32]);
+
+        assert!(reader.read_tlv().unwrap().is_none());
+    }
+
+    #[test]
+    fn iterator() {
+        let input = [0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03];
+        let reader = Reader::new(Cursor::new(input));
+        let tags = reader
+            .map(|tlv| tlv.map(|tlv| tlv.tag()))
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(tags, [Tag::Integer; 3]);
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        // announced length of 5, but only 2 content octets follow
+        let mut reader = Reader::new(Cursor::new([0x04, 0x05, 0x01, 0x02]));
+        assert!(reader.read_tlv().is_err());
+
+        // header itself cut short
+        let mut reader = Reader::new(Cursor::new([0x04]));
+        assert!(reader.read_tlv().is_err());
+    }
+}
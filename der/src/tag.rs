@@ -4,59 +4,224 @@ use crate::{Decodable, Decoder, Encodable, Encoder, Error, ErrorKind, Length, Re
 use core::{convert::TryFrom, fmt};
 
 /// Indicator bit for constructed form encoding (i.e. vs primitive form)
-const CONSTRUCTED_FLAG: u8 = 0b100000;
+pub(crate) const CONSTRUCTED_FLAG: u8 = 0b100000;
 
-/// Indicator bit for context-specific types
-const CONTEXT_SPECIFIC_FLAG: u8 = 0b10000000;
+/// ASN.1 tag classes, identified by the upper two bits of the identifier
+/// octet.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Class {
+    /// Universal class: types whose meaning is the same in all
+    /// applications, i.e. the ASN.1 built-in types.
+    Universal = 0b00,
+
+    /// Application class: types whose meaning is specific to an
+    /// application, e.g. the Kerberos and LDAP message types.
+    Application = 0b01,
+
+    /// Context-specific class: types whose meaning is specific to a given
+    /// structured type.
+    ContextSpecific = 0b10,
+
+    /// Private class: types whose meaning is specific to a given
+    /// enterprise.
+    Private = 0b11,
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Class::Universal => "UNIVERSAL",
+            Class::Application => "APPLICATION",
+            Class::ContextSpecific => "CONTEXT-SPECIFIC",
+            Class::Private => "PRIVATE",
+        })
+    }
+}
+
+/// ASN.1 tag numbers for the application, context-specific, and private
+/// classes.
+///
+/// Numbers up to `30` are encoded in the identifier octet itself; larger
+/// numbers use the multi-byte "high tag number" form, i.e. base 128
+/// continuation octets, and are presently limited to two of them (a
+/// maximum tag number of `16383`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct TagNumber(u16);
+
+impl TagNumber {
+    /// Maximum supported tag number.
+    pub const MAX: u16 = 16383;
+
+    /// Create a new [`TagNumber`].
+    ///
+    /// Panics if the given number is greater than [`TagNumber::MAX`]; for
+    /// a fallible conversion, use [`TryFrom`] instead.
+    // `const fn`s cannot return `Result`; out-of-range constants are a
+    // programmer error rather than untrusted input
+    #[allow(clippy::panic)]
+    pub const fn new(value: u16) -> Self {
+        match value {
+            0..=Self::MAX => Self(value),
+            _ => panic!("tag number out of range (maximum 16383)"),
+        }
+    }
+
+    /// Get the inner tag number value.
+    pub const fn value(self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for TagNumber {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<TagNumber> {
+        match value {
+            0..=Self::MAX => Ok(Self(value)),
+            _ => Err(ErrorKind::Overflow.into()),
+        }
+    }
+}
+
+impl fmt::Display for TagNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 /// ASN.1 tags.
 #[derive(Copy, Clone, Eq, PartialEq)]
-#[allow(clippy::identity_op)]
 #[non_exhaustive]
-#[repr(u8)]
 pub enum Tag {
     /// `BOOLEAN` tag.
-    Boolean = 0x01,
+    Boolean,
 
     /// `INTEGER` tag.
-    Integer = 0x02,
+    Integer,
 
     /// `BIT STRING` tag.
-    BitString = 0x03,
+    BitString,
 
     /// `OCTET STRING` tag.
-    OctetString = 0x04,
+    OctetString,
 
     /// `NULL` tag.
-    Null = 0x05,
+    Null,
 
     /// `OBJECT IDENTIFIER` tag.
-    ObjectIdentifier = 0x06,
+    ObjectIdentifier,
+
+    /// `REAL` tag.
+    Real,
+
+    /// `ENUMERATED` tag.
+    Enumerated,
+
+    /// `UTF8String` tag.
+    Utf8String,
+
+    /// `RELATIVE-OID` tag.
+    RelativeOid,
 
     /// `SEQUENCE` tag.
     ///
-    /// Note that the universal tag number for `SEQUENCE` is technically `0x10`
-    /// however we presently only support the constructed form, which has the
-    /// 6th bit (i.e. `0x20`) set.
-    Sequence = 0x10 | CONSTRUCTED_FLAG,
+    /// Note that the universal tag number for `SEQUENCE` is technically
+    /// `0x10` however we presently only support the constructed form,
+    /// which has the 6th bit (i.e. `0x20`) set.
+    Sequence,
+
+    /// `SET` and `SET OF` tag.
+    ///
+    /// As with `SEQUENCE`, the universal tag number is technically `0x11`,
+    /// however only the constructed form (6th bit set) is supported.
+    Set,
+
+    /// `NumericString` tag.
+    NumericString,
 
-    /// Context-specific tag (0) unique to a particular structure.
-    ContextSpecific0 = 0 | CONTEXT_SPECIFIC_FLAG | CONSTRUCTED_FLAG,
+    /// `PrintableString` tag.
+    PrintableString,
 
-    /// Context-specific tag (1) unique to a particular structure.
-    ContextSpecific1 = 1 | CONTEXT_SPECIFIC_FLAG | CONSTRUCTED_FLAG,
+    /// `TeletexString` tag.
+    TeletexString,
 
-    /// Context-specific tag (2) unique to a particular structure.
-    ContextSpecific2 = 2 | CONTEXT_SPECIFIC_FLAG | CONSTRUCTED_FLAG,
+    /// `VideotexString` tag.
+    VideotexString,
 
-    /// Context-specific tag (3) unique to a particular structure.
-    ContextSpecific3 = 3 | CONTEXT_SPECIFIC_FLAG | CONSTRUCTED_FLAG,
+    /// `IA5String` tag.
+    Ia5String,
+
+    /// `UTCTime` tag.
+    UtcTime,
+
+    /// `GeneralizedTime` tag.
+    GeneralizedTime,
+
+    /// `VisibleString` tag.
+    VisibleString,
+
+    /// `GeneralString` tag.
+    GeneralString,
+
+    /// `UniversalString` tag.
+    UniversalString,
+
+    /// `BMPString` tag.
+    BmpString,
+
+    /// Application class tag.
+    Application {
+        /// Is the inner value encoded in constructed form?
+        constructed: bool,
+
+        /// Tag number.
+        number: TagNumber,
+    },
+
+    /// Context-specific class tag, unique to a particular structure.
+    ContextSpecific {
+        /// Is the inner value encoded in constructed form?
+        constructed: bool,
+
+        /// Tag number.
+        number: TagNumber,
+    },
+
+    /// Private class tag.
+    Private {
+        /// Is the inner value encoded in constructed form?
+        constructed: bool,
+
+        /// Tag number.
+        number: TagNumber,
+    },
+}
+
+/// Whether a context-specific field uses `EXPLICIT` or `IMPLICIT` tagging.
+///
+/// `EXPLICIT` tagging wraps the complete encoding of the inner value in an
+/// additional TLV, whereas `IMPLICIT` tagging replaces the inner value's
+/// tag, leaving its content octets as the field's contents.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TagMode {
+    /// `EXPLICIT` tagging: the value is wrapped in an additional TLV.
+    ///
+    /// This is the default tagging environment in ASN.1.
+    #[default]
+    Explicit,
+
+    /// `IMPLICIT` tagging: the value's tag is replaced.
+    Implicit,
 }
 
 impl TryFrom<u8> for Tag {
     type Error = Error;
 
     fn try_from(byte: u8) -> Result<Tag> {
+        let constructed = byte & CONSTRUCTED_FLAG != 0;
+        let number = byte & 0b11111;
+
         match byte {
             0x01 => Ok(Tag::Boolean),
             0x02 => Ok(Tag::Integer),
@@ -64,12 +229,45 @@ impl TryFrom<u8> for Tag {
             0x04 => Ok(Tag::OctetString),
             0x05 => Ok(Tag::Null),
             0x06 => Ok(Tag::ObjectIdentifier),
+            0x09 => Ok(Tag::Real),
+            0x0A => Ok(Tag::Enumerated),
+            0x0C => Ok(Tag::Utf8String),
+            0x0D => Ok(Tag::RelativeOid),
+            0x12 => Ok(Tag::NumericString),
+            0x13 => Ok(Tag::PrintableString),
+            0x14 => Ok(Tag::TeletexString),
+            0x15 => Ok(Tag::VideotexString),
+            0x16 => Ok(Tag::Ia5String),
+            0x17 => Ok(Tag::UtcTime),
+            0x18 => Ok(Tag::GeneralizedTime),
+            0x1A => Ok(Tag::VisibleString),
+            0x1B => Ok(Tag::GeneralString),
+            0x1C => Ok(Tag::UniversalString),
+            0x1E => Ok(Tag::BmpString),
             0x30 => Ok(Tag::Sequence),
-            0xA0 => Ok(Tag::ContextSpecific0),
-            0xA1 => Ok(Tag::ContextSpecific1),
-            0xA2 => Ok(Tag::ContextSpecific2),
-            0xA3 => Ok(Tag::ContextSpecific3),
-            _ => Err(ErrorKind::UnknownTag { byte }.into()),
+            0x31 => Ok(Tag::Set),
+            // multi-byte (high tag number) forms require the continuation
+            // octets and can only be decoded via [`Decodable`]
+            _ if number == 0b11111 => Err(ErrorKind::UnknownTag { byte }.into()),
+            _ => {
+                let number = TagNumber(number.into());
+
+                match byte >> 6 {
+                    0b01 => Ok(Tag::Application {
+                        constructed,
+                        number,
+                    }),
+                    0b10 => Ok(Tag::ContextSpecific {
+                        constructed,
+                        number,
+                    }),
+                    0b11 => Ok(Tag::Private {
+                        constructed,
+                        number,
+                    }),
+                    _ => Err(ErrorKind::UnknownTag { byte }.into()),
+                }
+            }
         }
     }
 }
@@ -90,6 +288,129 @@ impl Tag {
         }
     }
 
+    /// Get the constructed context-specific [`Tag`] for the provided tag
+    /// number, if it is in the supported range.
+    pub fn context_specific(number: u16) -> Option<Tag> {
+        TagNumber::try_from(number)
+            .map(|number| Self::context(number, true))
+            .ok()
+    }
+
+    /// Create an `APPLICATION` tag with the given tag number and
+    /// constructed bit.
+    pub const fn application(number: TagNumber, constructed: bool) -> Tag {
+        Tag::Application {
+            constructed,
+            number,
+        }
+    }
+
+    /// Create a context-specific tag with the given tag number and
+    /// constructed bit, e.g. `Tag::context(TagNumber::new(3), true)` for
+    /// the constructed form of `[3]`.
+    pub const fn context(number: TagNumber, constructed: bool) -> Tag {
+        Tag::ContextSpecific {
+            constructed,
+            number,
+        }
+    }
+
+    /// Create a `PRIVATE` tag with the given tag number and constructed
+    /// bit.
+    pub const fn private(number: TagNumber, constructed: bool) -> Tag {
+        Tag::Private {
+            constructed,
+            number,
+        }
+    }
+
+    /// Get the [`Class`] of this [`Tag`].
+    pub const fn class(self) -> Class {
+        match self {
+            Tag::Application { .. } => Class::Application,
+            Tag::ContextSpecific { .. } => Class::ContextSpecific,
+            Tag::Private { .. } => Class::Private,
+            _ => Class::Universal,
+        }
+    }
+
+    /// Get the tag number of this [`Tag`].
+    pub const fn number(self) -> u16 {
+        match self {
+            Tag::Application { number, .. }
+            | Tag::ContextSpecific { number, .. }
+            | Tag::Private { number, .. } => number.value(),
+            _ => (self.octet() & 0b11111) as u16,
+        }
+    }
+
+    /// Is the value identified by this [`Tag`] encoded in constructed form?
+    pub const fn is_constructed(self) -> bool {
+        self.octet() & CONSTRUCTED_FLAG != 0
+    }
+
+    /// Get the initial identifier octet for this [`Tag`].
+    ///
+    /// Tag numbers greater than `30` use the "high tag number" form: the
+    /// number bits of the initial octet are all ones and the number itself
+    /// follows in base 128 continuation octets.
+    pub const fn octet(self) -> u8 {
+        match self {
+            Tag::Boolean => 0x01,
+            Tag::Integer => 0x02,
+            Tag::BitString => 0x03,
+            Tag::OctetString => 0x04,
+            Tag::Null => 0x05,
+            Tag::ObjectIdentifier => 0x06,
+            Tag::Real => 0x09,
+            Tag::Enumerated => 0x0A,
+            Tag::Utf8String => 0x0C,
+            Tag::RelativeOid => 0x0D,
+            Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
+            Tag::Set => 0x11 | CONSTRUCTED_FLAG,
+            Tag::NumericString => 0x12,
+            Tag::PrintableString => 0x13,
+            Tag::TeletexString => 0x14,
+            Tag::VideotexString => 0x15,
+            Tag::Ia5String => 0x16,
+            Tag::UtcTime => 0x17,
+            Tag::GeneralizedTime => 0x18,
+            Tag::VisibleString => 0x1A,
+            Tag::GeneralString => 0x1B,
+            Tag::UniversalString => 0x1C,
+            Tag::BmpString => 0x1E,
+            Tag::Application {
+                constructed,
+                number,
+            }
+            | Tag::ContextSpecific {
+                constructed,
+                number,
+            }
+            | Tag::Private {
+                constructed,
+                number,
+            } => {
+                let number_bits = match number.value() {
+                    0..=30 => number.value() as u8,
+                    _ => 0b11111,
+                };
+
+                ((self.class() as u8) << 6) | (constructed as u8) << 5 | number_bits
+            }
+        }
+    }
+
+    /// Compute the encoded length of this [`Tag`] in a `const` context;
+    /// the `const`-compatible equivalent of its [`Encodable`] impl.
+    pub const fn const_encoded_len(self) -> Length {
+        match self.number() {
+            0..=30 => Length::new(1),
+            31..=127 => Length::new(2),
+            _ => Length::new(3),
+        }
+    }
+
     /// Names of ASN.1 type which corresponds to a given [`Tag`].
     pub fn type_name(self) -> &'static str {
         match self {
@@ -99,39 +420,239 @@ impl Tag {
             Self::OctetString => "OCTET STRING",
             Self::Null => "NULL",
             Self::ObjectIdentifier => "OBJECT IDENTIFIER",
+            Self::Real => "REAL",
+            Self::Enumerated => "ENUMERATED",
+            Self::Utf8String => "UTF8String",
+            Self::RelativeOid => "RELATIVE-OID",
+            Self::NumericString => "NumericString",
+            Self::PrintableString => "PrintableString",
+            Self::TeletexString => "TeletexString",
+            Self::VideotexString => "VideotexString",
+            Self::Ia5String => "IA5String",
+            Self::UtcTime => "UTCTime",
+            Self::GeneralizedTime => "GeneralizedTime",
+            Self::VisibleString => "VisibleString",
+            Self::GeneralString => "GeneralString",
+            Self::UniversalString => "UniversalString",
+            Self::BmpString => "BMPString",
             Self::Sequence => "SEQUENCE",
-            Self::ContextSpecific0 => "Context Specific 0",
-            Self::ContextSpecific1 => "Context Specific 1",
-            Self::ContextSpecific2 => "Context Specific 2",
-            Self::ContextSpecific3 => "Context Specific 3",
+            Self::Set => "SET",
+            Self::Application { .. } => "APPLICATION",
+            Self::ContextSpecific { .. } => "CONTEXT-SPECIFIC",
+            Self::Private { .. } => "PRIVATE",
         }
     }
 }
 
 impl Decodable<'_> for Tag {
     fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
-        decoder.byte().and_then(Self::try_from)
+        let first = decoder.byte()?;
+
+        // number bits of all ones indicate the multi-byte
+        // "high tag number" form
+        if first & 0b11111 != 0b11111 || first >> 6 == 0b00 {
+            return Self::try_from(first);
+        }
+
+        let mut number: u16 = 0;
+
+        loop {
+            let byte = decoder.byte()?;
+
+            // the number must be encoded minimally: no leading zero
+            // continuation octets, and numbers below 31 use the low bits
+            // of the initial octet instead
+            if number == 0 && byte == 0x80 {
+                return Err(ErrorKind::Noncanonical.into());
+            }
+
+            number = (number << 7) | (byte & 0x7F) as u16;
+
+            if number > TagNumber::MAX >> 7 && byte & 0x80 != 0 {
+                return Err(ErrorKind::Overflow.into());
+            }
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        if number < 31 {
+            return Err(ErrorKind::Noncanonical.into());
+        }
+
+        let constructed = first & CONSTRUCTED_FLAG != 0;
+        let number = TagNumber::try_from(number)?;
+
+        match first >> 6 {
+            0b01 => Ok(Tag::Application {
+                constructed,
+                number,
+            }),
+            0b10 => Ok(Tag::ContextSpecific {
+                constructed,
+                number,
+            }),
+            _ => Ok(Tag::Private {
+                constructed,
+                number,
+            }),
+        }
     }
 }
 
 impl Encodable for Tag {
     fn encoded_len(&self) -> Result<Length> {
-        Ok(1u8.into())
+        match self.number() {
+            0..=30 => Ok(1u8.into()),
+            31..=127 => Ok(2u8.into()),
+            _ => Ok(3u8.into()),
+        }
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        encoder.byte(*self as u8)
+        encoder.byte(self.octet())?;
+
+        match self.number() {
+            0..=30 => Ok(()),
+            number @ 31..=127 => encoder.byte(number as u8),
+            number => {
+                encoder.byte(0x80 | (number >> 7) as u8)?;
+                encoder.byte((number & 0x7F) as u8)
+            }
+        }
     }
 }
 
 impl fmt::Display for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.type_name())
+        match self.class() {
+            Class::Universal => f.write_str(self.type_name()),
+            class => write!(f, "{} [{}]", class, self.number()),
+        }
     }
 }
 
 impl fmt::Debug for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Tag(0x{:02x}: {})", *self as u8, self.type_name())
+        write!(f, "Tag(0x{:02x}: {})", self.octet(), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Class, Tag, TagNumber};
+    use crate::{Decodable, Encodable};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn identifier_octet_roundtrip() {
+        for &octet in &[0x01, 0x30, 0x41, 0x61, 0x80, 0xA3, 0xBE, 0xC0, 0xFE] {
+            assert_eq!(Tag::try_from(octet).unwrap().octet(), octet);
+        }
+    }
+
+    #[test]
+    fn classes_and_accessors() {
+        let tag = Tag::try_from(0x61).unwrap();
+        assert_eq!(tag.class(), Class::Application);
+        assert_eq!(tag.number(), 1);
+        assert!(tag.is_constructed());
+
+        let tag = Tag::Private {
+            constructed: false,
+            number: TagNumber::new(30),
+        };
+        assert_eq!(tag.octet(), 0xDE);
+        assert_eq!(tag.class(), Class::Private);
+        assert!(!tag.is_constructed());
+
+        assert_eq!(Tag::Sequence.class(), Class::Universal);
+        assert_eq!(Tag::Sequence.number(), 0x10);
+        assert!(Tag::Sequence.is_constructed());
+        assert!(!Tag::Boolean.is_constructed());
+    }
+
+    #[test]
+    fn constructors() {
+        // constructed `[3]`, as used by implicitly tagged fields
+        let tag = Tag::context(TagNumber::new(3), true);
+        assert_eq!(tag.octet(), 0xA3);
+        assert_eq!(tag, Tag::context_specific(3).unwrap());
+
+        let tag = Tag::application(TagNumber::new(1), false);
+        assert_eq!(tag.octet(), 0x41);
+
+        const PRIVATE: Tag = Tag::private(TagNumber::new(30), false);
+        assert_eq!(PRIVATE.octet(), 0xDE);
+    }
+
+    #[test]
+    fn high_tag_numbers() {
+        let mut buffer = [0u8; 4];
+
+        for &(number, encoding) in &[
+            (30u16, &[0xBE][..]),
+            (31, &[0xBF, 0x1F]),
+            (127, &[0xBF, 0x7F]),
+            (128, &[0xBF, 0x81, 0x00]),
+            (16383, &[0xBF, 0xFF, 0x7F]),
+        ] {
+            let tag = Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber::new(number),
+            };
+
+            let encoded = tag.encode_to_slice(&mut buffer).unwrap();
+            assert_eq!(encoded, encoding, "bad encoding for tag number {}", number);
+
+            let decoded = Tag::from_bytes(encoded).unwrap();
+            assert_eq!(decoded, tag, "bad decoding for tag number {}", number);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_high_tag_numbers() {
+        assert!(TagNumber::try_from(16384).is_err());
+
+        // initial octet alone is truncated
+        assert!(Tag::from_bytes(&[0xBF]).is_err());
+
+        // number 16384 exceeds the supported range
+        assert!(Tag::from_bytes(&[0xBF, 0x81, 0x80, 0x00]).is_err());
+
+        // leading zero continuation octet
+        assert!(Tag::from_bytes(&[0xBF, 0x80, 0x7F]).is_err());
+
+        // number 30 must use the low bits of the initial octet
+        assert!(Tag::from_bytes(&[0xBF, 0x1E]).is_err());
+    }
+
+    #[test]
+    fn assert_eq_captures_expected_and_actual() {
+        use crate::ErrorKind;
+
+        assert_eq!(Tag::Integer, Tag::Integer.assert_eq(Tag::Integer).unwrap());
+
+        let err = Tag::Boolean.assert_eq(Tag::Integer).err().unwrap();
+        assert_eq!(
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::Integer),
+                actual: Tag::Boolean
+            },
+            err.kind()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn tag_mismatch_display_uses_names() {
+        use alloc::string::ToString;
+
+        let err = Tag::Boolean.assert_eq(Tag::Integer).err().unwrap();
+        assert_eq!(
+            "unexpected ASN.1 DER tag: expected INTEGER, got BOOLEAN",
+            err.to_string()
+        );
     }
 }
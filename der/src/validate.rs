@@ -0,0 +1,340 @@
+//! Schema-agnostic DER canonicality validation.
+
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+
+/// Indicator bit for constructed form encoding
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Tag octet for a universal `BOOLEAN`
+const BOOLEAN_TAG: u8 = 0x01;
+
+/// Tag octet for a universal constructed `SET` / `SET OF`
+const SET_TAG: u8 = 0x31;
+
+/// Maximum nesting depth, matching [`Decoder`]'s default limit so deeply
+/// nested inputs cannot exhaust the stack
+///
+/// [`Decoder`]: crate::Decoder
+const DEPTH_LIMIT: usize = 32;
+
+/// Check an arbitrary BER/DER input for DER canonicality violations
+/// without requiring a schema, e.g. for conformance testing of other
+/// encoders.
+///
+/// Returns the list of [`Violation`]s found, or `Ok(())` if the input is
+/// canonical DER.
+///
+/// ```
+/// // BOOLEAN TRUE encoded as 0x01 rather than the canonical 0xFF
+/// let violations = der::validate(&[0x01, 0x01, 0x01]).err().unwrap();
+/// assert_eq!(violations.len(), 1);
+/// ```
+pub fn validate(input: &[u8]) -> core::result::Result<(), Vec<Violation>> {
+    let mut violations = Vec::new();
+    scan_body(input, 0, 0, &mut violations);
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// DER canonicality violation found at a given position.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Violation {
+    /// Kind of violation
+    kind: ViolationKind,
+
+    /// Offset of the start of the offending TLV record
+    position: usize,
+}
+
+impl Violation {
+    /// Get the kind of violation.
+    pub fn kind(self) -> ViolationKind {
+        self.kind
+    }
+
+    /// Get the offset of the start of the offending TLV record.
+    pub fn position(self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at DER byte {}", self.kind, self.position)
+    }
+}
+
+/// Kinds of DER canonicality violations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// Indefinite length, which BER permits but DER forbids
+    IndefiniteLength,
+
+    /// Length not encoded in the minimal possible form
+    NonMinimalLength,
+
+    /// `BOOLEAN` with a `TRUE` value other than `0xFF`
+    NonCanonicalBoolean,
+
+    /// `SET OF` whose element encodings are not in ascending order
+    UnsortedSetOf,
+
+    /// Constructed values nested more deeply than the scanner's depth limit
+    NestedTooDeep,
+
+    /// Structure which could not be parsed as TLV records at all
+    Malformed,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::IndefiniteLength => "indefinite length",
+            Self::NonMinimalLength => "non-minimal length",
+            Self::NonCanonicalBoolean => "non-canonical BOOLEAN",
+            Self::UnsortedSetOf => "unsorted SET OF",
+            Self::NestedTooDeep => "nested too deeply",
+            Self::Malformed => "malformed TLV structure",
+        })
+    }
+}
+
+/// Scan every TLV record in `body`, which starts at absolute offset `base`
+/// and is nested `depth` levels deep, returning the range of each top-level
+/// record relative to `body`.
+fn scan_body(
+    body: &[u8],
+    base: usize,
+    depth: usize,
+    violations: &mut Vec<Violation>,
+) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+
+    while pos < body.len() {
+        match scan_tlv(body, pos, base, depth, violations) {
+            Some(end) => {
+                ranges.push((pos, end));
+                pos = end;
+            }
+            None => break,
+        }
+    }
+
+    ranges
+}
+
+/// Scan the single TLV record starting at `pos` within `body`, returning
+/// the offset just past it, or `None` if scanning cannot continue.
+fn scan_tlv(
+    body: &[u8],
+    pos: usize,
+    base: usize,
+    depth: usize,
+    violations: &mut Vec<Violation>,
+) -> Option<usize> {
+    let position = base + pos;
+
+    let first = match body.get(pos) {
+        Some(&byte) => byte,
+        None => {
+            violations.push(Violation {
+                kind: ViolationKind::Malformed,
+                position,
+            });
+            return None;
+        }
+    };
+
+    let mut pos = pos + 1;
+
+    // Skip the continuation octets of a high tag number (X.690 §8.1.2.4)
+    if first & 0b11111 == 0b11111 {
+        loop {
+            match body.get(pos) {
+                Some(&byte) => {
+                    pos += 1;
+
+                    if byte & 0x80 == 0 {
+                        break;
+                    }
+                }
+                None => {
+                    violations.push(Violation {
+                        kind: ViolationKind::Malformed,
+                        position,
+                    });
+                    return None;
+                }
+            }
+        }
+    }
+
+    let (length, pos) = match scan_length(body, pos) {
+        Ok((length, pos)) => (length, pos),
+        Err(kind) => {
+            violations.push(Violation { kind, position });
+            return None;
+        }
+    };
+
+    let value = match pos.checked_add(length).and_then(|end| body.get(pos..end)) {
+        Some(value) => value,
+        None => {
+            violations.push(Violation {
+                kind: ViolationKind::Malformed,
+                position,
+            });
+            return None;
+        }
+    };
+
+    if first & CONSTRUCTED_FLAG != 0 {
+        if depth >= DEPTH_LIMIT {
+            violations.push(Violation {
+                kind: ViolationKind::NestedTooDeep,
+                position,
+            });
+            return None;
+        }
+
+        let children = scan_body(value, base + pos, depth + 1, violations);
+
+        // DER requires the elements of a `SET OF` to be sorted in
+        // ascending order of their encodings (X.690 §11.6)
+        if first == SET_TAG {
+            let sorted = children
+                .windows(2)
+                .all(|pair| value[pair[0].0..pair[0].1] <= value[pair[1].0..pair[1].1]);
+
+            if !sorted {
+                violations.push(Violation {
+                    kind: ViolationKind::UnsortedSetOf,
+                    position,
+                });
+            }
+        }
+    } else if first == BOOLEAN_TAG && value.len() == 1 && !matches!(value[0], 0x00 | 0xFF) {
+        violations.push(Violation {
+            kind: ViolationKind::NonCanonicalBoolean,
+            position,
+        });
+    }
+
+    Some(pos + length)
+}
+
+/// Scan the length octets starting at `pos`, returning the decoded length
+/// and the offset just past them.
+fn scan_length(body: &[u8], pos: usize) -> core::result::Result<(usize, usize), ViolationKind> {
+    let first = *body.get(pos).ok_or(ViolationKind::Malformed)?;
+    let pos = pos + 1;
+
+    match first {
+        len if len < 0x80 => Ok((len as usize, pos)),
+        0x80 => Err(ViolationKind::IndefiniteLength),
+        tag => {
+            let nbytes = (tag & 0x7F) as usize;
+
+            let octets = body
+                .get(pos..pos + nbytes)
+                .filter(|octets| octets.len() <= 8)
+                .ok_or(ViolationKind::Malformed)?;
+
+            // Always fits: at most 8 octets are accepted above
+            let mut length = 0u64;
+
+            for &octet in octets {
+                length = (length << 8) | u64::from(octet);
+            }
+
+            let length = usize::try_from(length).map_err(|_| ViolationKind::Malformed)?;
+
+            // Minimal form: no leading zero octet, and no long form for
+            // lengths which fit in the short form (X.690 §10.1)
+            if octets[0] == 0 || length < 0x80 {
+                Err(ViolationKind::NonMinimalLength)
+            } else {
+                Ok((length, pos + nbytes))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, ViolationKind};
+
+    #[test]
+    fn accepts_canonical_der() {
+        // SEQUENCE { INTEGER 1, BOOLEAN TRUE }
+        assert!(validate(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF]).is_ok());
+    }
+
+    #[test]
+    fn rejects_non_minimal_length() {
+        let violations = validate(&[0x02, 0x81, 0x01, 0x2A]).err().unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::NonMinimalLength);
+        assert_eq!(violations[0].position(), 0);
+    }
+
+    #[test]
+    fn rejects_indefinite_length() {
+        let violations = validate(&[0x30, 0x80, 0x00, 0x00]).err().unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn rejects_non_canonical_boolean() {
+        let violations = validate(&[0x30, 0x03, 0x01, 0x01, 0x01]).err().unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::NonCanonicalBoolean);
+        assert_eq!(violations[0].position(), 2);
+    }
+
+    #[test]
+    fn rejects_unsorted_set_of() {
+        let violations = validate(&[0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01])
+            .err()
+            .unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::UnsortedSetOf);
+        assert_eq!(violations[0].position(), 0);
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let violations = validate(&[0x30, 0x03, 0x02, 0x01]).err().unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::Malformed);
+    }
+
+    #[test]
+    fn rejects_excessive_nesting() {
+        // 64 nested empty SEQUENCEs: scanning must stop at the depth limit
+        // rather than recursing once per level
+        let mut input = [0u8; 128];
+        for (i, chunk) in input.chunks_exact_mut(2).enumerate() {
+            chunk[0] = 0x30;
+            chunk[1] = (126 - i * 2) as u8;
+        }
+
+        let violations = validate(&input).err().unwrap();
+        assert_eq!(violations[0].kind(), ViolationKind::NestedTooDeep);
+
+        // nesting within the limit is still scanned in full
+        assert!(validate(&input[96..]).is_ok());
+    }
+
+    #[test]
+    fn reports_multiple_violations() {
+        // SEQUENCE { BOOLEAN 0x01, BOOLEAN 0x42 }
+        let violations = validate(&[0x30, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x42])
+            .err()
+            .unwrap();
+        assert_eq!(violations.len(), 2);
+    }
+}
@@ -0,0 +1,159 @@
+//! Tests for custom derive support
+
+#![cfg(feature = "derive")]
+// `synstructure` expands derived impls inside a named const
+#![allow(non_local_definitions)]
+
+use core::convert::TryFrom;
+use der::{Choice, ContextSpecific, Decodable, Decoder, Encodable, Enumerated, Message, OctetString};
+
+/// Message with a context-specific `OPTIONAL` field and a `DEFAULT` field:
+///
+/// ```text
+/// Example ::= SEQUENCE {
+///     data [0] OCTET STRING OPTIONAL,
+///     flag BOOLEAN DEFAULT FALSE
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Message)]
+pub struct Example<'a> {
+    #[asn1(context_specific = "0")]
+    pub data: Option<OctetString<'a>>,
+
+    #[asn1(default = "bool::default")]
+    pub flag: bool,
+}
+
+/// `Example` with all fields present
+const EXAMPLE_FULL: &[u8] = &[
+    0x30, 0x09, 0xA0, 0x04, 0x04, 0x02, 0xAA, 0xBB, 0x01, 0x01, 0xFF,
+];
+
+/// `Example` with the `OPTIONAL` field absent and the `DEFAULT` field
+/// equal to its default (and therefore omitted)
+const EXAMPLE_EMPTY: &[u8] = &[0x30, 0x00];
+
+#[test]
+fn decode_context_specific_and_default_fields() {
+    let example = Example::from_bytes(EXAMPLE_FULL).unwrap();
+    assert_eq!(example.data.unwrap().as_bytes(), &[0xAA, 0xBB]);
+    assert!(example.flag);
+
+    let example = Example::from_bytes(EXAMPLE_EMPTY).unwrap();
+    assert_eq!(example.data, None);
+    assert!(!example.flag);
+}
+
+#[test]
+fn encode_context_specific_and_default_fields() {
+    let mut buffer = [0u8; 16];
+
+    let example = Example {
+        data: Some(OctetString::new(&[0xAA, 0xBB]).unwrap()),
+        flag: true,
+    };
+    assert_eq!(example.encode_to_slice(&mut buffer).unwrap(), EXAMPLE_FULL);
+
+    let example = Example {
+        data: None,
+        flag: false,
+    };
+    assert_eq!(example.encode_to_slice(&mut buffer).unwrap(), EXAMPLE_EMPTY);
+}
+
+/// `CHOICE` with universal and context-specific alternatives:
+///
+/// ```text
+/// Value ::= CHOICE {
+///     flag BOOLEAN,
+///     count INTEGER,
+///     extra [0] ANY
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+pub enum Value<'a> {
+    Flag(bool),
+    Count(i8),
+    #[asn1(context_specific = "0")]
+    Extra(ContextSpecific<'a>),
+}
+
+#[test]
+fn decode_choice_variants() {
+    assert_eq!(Value::from_bytes(&[0x01, 0x01, 0xFF]).unwrap(), Value::Flag(true));
+    assert_eq!(Value::from_bytes(&[0x02, 0x01, 0x2A]).unwrap(), Value::Count(42));
+
+    match Value::from_bytes(&[0xA0, 0x03, 0x02, 0x01, 0x02]).unwrap() {
+        Value::Extra(field) => {
+            assert_eq!(field.tag_number(), 0);
+            assert_eq!(i8::try_from(field.value()).unwrap(), 2);
+        }
+        other => panic!("unexpected variant: {:?}", other),
+    }
+
+    // NULL is not a variant of the `CHOICE`
+    assert!(Value::from_bytes(&[0x05, 0x00]).is_err());
+}
+
+#[test]
+fn encode_choice_variants() {
+    let mut buffer = [0u8; 8];
+    assert_eq!(
+        Value::Count(42).encode_to_slice(&mut buffer).unwrap(),
+        &[0x02, 0x01, 0x2A]
+    );
+}
+
+#[test]
+fn choice_variants_are_optional() {
+    let mut decoder = Decoder::new(&[0x05, 0x00]);
+    assert_eq!(decoder.optional::<Value<'_>>().unwrap(), None);
+}
+
+/// `ENUMERATED` with a catch-all for unknown values:
+///
+/// ```text
+/// KeyType ::= ENUMERATED { rsa(0), ec(1), unknown(255) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Enumerated)]
+pub enum KeyType {
+    Rsa = 0,
+    Ec = 1,
+    #[asn1(catch_all)]
+    Unknown = 255,
+}
+
+/// `ENUMERATED` without a catch-all
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Enumerated)]
+pub enum Version {
+    V1 = 0,
+    V2 = 1,
+}
+
+#[test]
+fn decode_enumerated_variants() {
+    assert_eq!(KeyType::from_bytes(&[0x0A, 0x01, 0x00]).unwrap(), KeyType::Rsa);
+    assert_eq!(KeyType::from_bytes(&[0x0A, 0x01, 0x01]).unwrap(), KeyType::Ec);
+
+    // unknown values map to the `#[asn1(catch_all)]` variant...
+    assert_eq!(
+        KeyType::from_bytes(&[0x0A, 0x01, 0x07]).unwrap(),
+        KeyType::Unknown
+    );
+
+    // ...and are rejected without one
+    assert!(Version::from_bytes(&[0x0A, 0x01, 0x07]).is_err());
+}
+
+#[test]
+fn encode_enumerated_variants() {
+    let mut buffer = [0u8; 8];
+    assert_eq!(
+        KeyType::Ec.encode_to_slice(&mut buffer).unwrap(),
+        &[0x0A, 0x01, 0x01]
+    );
+    assert_eq!(
+        KeyType::Unknown.encode_to_slice(&mut buffer).unwrap(),
+        &[0x0A, 0x02, 0x00, 0xFF]
+    );
+}
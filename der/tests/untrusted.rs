@@ -0,0 +1,77 @@
+//! Panic-freedom smoke tests: decode pseudo-random and mutated inputs,
+//! expecting errors rather than panics.
+//!
+//! This complements the `cargo fuzz` targets in `der/fuzz`, which exercise
+//! the same decoders with coverage-guided input generation.
+
+use der::Decodable;
+
+/// Deterministic xorshift-style pseudo-random byte stream.
+struct Rng(u64);
+
+impl Rng {
+    fn next_byte(&mut self) -> u8 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 24) as u8
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        for byte in buf {
+            *byte = self.next_byte();
+        }
+    }
+}
+
+/// Attempt to decode the given input as each of the fuzzed types.
+fn decode_all(input: &[u8]) {
+    if let Ok(any) = der::Any::from_bytes(input) {
+        let _ = any.header();
+    }
+
+    if let Ok(seq) = der::Sequence::from_bytes(input) {
+        for _ in seq.elements() {}
+    }
+
+    let _ = i128::from_bytes(input);
+    let _ = u128::from_bytes(input);
+    let _ = der::UIntBytes::from_bytes(input);
+
+    #[cfg(feature = "oid")]
+    let _ = der::ObjectIdentifier::from_bytes(input);
+}
+
+#[test]
+fn random_inputs_never_panic() {
+    let mut rng = Rng(0xDEC0_DE00_0000_0001);
+    let mut buf = [0u8; 64];
+
+    for len in 0..buf.len() {
+        for _ in 0..100 {
+            rng.fill(&mut buf[..len]);
+            decode_all(&buf[..len]);
+        }
+    }
+}
+
+#[test]
+fn mutated_inputs_never_panic() {
+    /// Well-formed seed: `rsaEncryption` `AlgorithmIdentifier`
+    const SEED: &[u8] = &[
+        0x30, 0x0D, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x05, 0x00,
+    ];
+
+    let mut buf = [0u8; 15];
+
+    // flip every bit of the seed individually, then try every truncation
+    for bit in 0..SEED.len() * 8 {
+        buf.copy_from_slice(SEED);
+        buf[bit / 8] ^= 1 << (bit % 8);
+        decode_all(&buf);
+    }
+
+    for len in 0..SEED.len() {
+        decode_all(&SEED[..len]);
+    }
+}
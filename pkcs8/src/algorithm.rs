@@ -136,6 +136,12 @@ impl From<ObjectIdentifier> for AlgorithmParameters {
     }
 }
 
+impl der::Choice<'_> for AlgorithmParameters {
+    fn can_decode(tag: der::Tag) -> bool {
+        matches!(tag, der::Tag::Null | der::Tag::ObjectIdentifier)
+    }
+}
+
 impl TryFrom<der::Any<'_>> for AlgorithmParameters {
     type Error = der::Error;
 